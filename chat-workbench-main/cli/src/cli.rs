@@ -1,4 +1,10 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+
+/// Top-level subcommand names as clap will parse them (kebab-case), used to
+/// decide whether a positional token is a real command or an alias candidate.
+pub const BUILTIN_COMMANDS: &[&str] = &[
+    "deploy", "dev", "db", "deps", "clean", "security", "monitor", "ci", "config", "doctor", "env", "init", "version",
+];
 
 #[derive(Parser)]
 #[command(name = "cwb")]
@@ -28,6 +34,43 @@ pub struct Cli {
     /// Configuration file path
     #[arg(long, global = true)]
     pub config: Option<String>,
+
+    /// Per-command timeout in seconds; timed-out commands are killed and retried per policy
+    #[arg(long, global = true)]
+    pub timeout: Option<u64>,
+
+    /// Output format: human-readable text, or structured JSON for scripting
+    /// against from CI
+    #[arg(long, global = true, value_enum)]
+    pub output: Option<OutputFormat>,
+}
+
+impl Cli {
+    /// Build the default `ExecPolicy` for this invocation from the global
+    /// `--timeout` flag; retries are opt-in per call site via `CommandBuilder`.
+    pub fn exec_policy(&self) -> crate::utils::executor::ExecPolicy {
+        let mut policy = crate::utils::executor::ExecPolicy::new();
+        if let Some(secs) = self.timeout {
+            policy = policy.with_timeout(std::time::Duration::from_secs(secs));
+        }
+        policy
+    }
+
+    pub fn output_format(&self) -> OutputFormat {
+        self.output.unwrap_or(OutputFormat::Text)
+    }
+
+    pub fn json_output(&self) -> bool {
+        self.output_format() == OutputFormat::Json
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-oriented text (the default)
+    Text,
+    /// Machine-readable JSON, for scripting and CI
+    Json,
 }
 
 #[derive(Subcommand, Clone)]
@@ -73,10 +116,60 @@ pub enum Commands {
     /// Diagnose setup issues
     Doctor,
 
+    /// Manage named deployment environments (a separate, lighter-weight
+    /// `cwb.yaml` registry from the per-deploy `config.yaml`)
+    #[command(subcommand)]
+    Env(EnvCommands),
+
+    /// Initialize a new `cwb.yaml` environment registry
+    Init {
+        /// Project name (defaults to the current directory name)
+        name: Option<String>,
+
+        /// Project type/template
+        #[arg(long, default_value = "fullstack")]
+        project_type: String,
+    },
+
     /// Show version information
     Version,
 }
 
+#[derive(Subcommand, Clone)]
+pub enum EnvCommands {
+    /// List configured environments
+    List,
+
+    /// Create a new environment
+    Create {
+        /// Environment name
+        name: String,
+
+        /// Copy AWS/GCP/kube settings from an existing environment instead
+        /// of prompting/prefilling from ambient tool state
+        #[arg(long)]
+        from: Option<String>,
+    },
+
+    /// Switch the current environment
+    Switch {
+        /// Environment name
+        name: String,
+    },
+
+    /// Delete an environment
+    Delete {
+        /// Environment name
+        name: String,
+    },
+
+    /// Show environment details
+    Show {
+        /// Environment name (defaults to the current environment)
+        name: Option<String>,
+    },
+}
+
 #[derive(Subcommand, Clone)]
 pub enum DeployCommands {
     /// Deploy stack(s)
@@ -84,9 +177,24 @@ pub enum DeployCommands {
         /// Stack name to deploy (optional)
         stack: Option<String>,
 
-        /// Deploy all stacks
+        /// Deploy all stacks, ordered by their CDK-declared dependencies
         #[arg(long)]
         all: bool,
+
+        /// CloudFormation service role to assume for this deployment
+        /// (falls back to the environment's `cloudformationRoleArn` in config.yaml)
+        #[arg(long)]
+        role_arn: Option<String>,
+
+        /// Max stacks to deploy concurrently when `--all` is set, subject to
+        /// dependency order (defaults to the number of CPUs)
+        #[arg(long)]
+        concurrency: Option<usize>,
+
+        /// With `--all`, stop scheduling new stacks after the first failure
+        /// instead of letting independent branches keep deploying
+        #[arg(long)]
+        fail_fast: bool,
     },
 
     /// Destroy stack(s)
@@ -94,9 +202,24 @@ pub enum DeployCommands {
         /// Stack name to destroy (optional)
         stack: Option<String>,
 
-        /// Destroy all stacks
+        /// Destroy all stacks, in reverse dependency order
         #[arg(long)]
         all: bool,
+
+        /// CloudFormation service role to assume for this destroy
+        /// (falls back to the environment's `cloudformationRoleArn` in config.yaml)
+        #[arg(long)]
+        role_arn: Option<String>,
+
+        /// Max stacks to destroy concurrently when `--all` is set, subject
+        /// to dependency order (defaults to the number of CPUs)
+        #[arg(long)]
+        concurrency: Option<usize>,
+
+        /// With `--all`, stop scheduling new stacks after the first failure
+        /// instead of letting independent branches keep destroying
+        #[arg(long)]
+        fail_fast: bool,
     },
 
     /// Show deployment status
@@ -113,6 +236,11 @@ pub enum DeployCommands {
         /// AWS region
         #[arg(long)]
         region: Option<String>,
+
+        /// CloudFormation service role to assume for this bootstrap
+        /// (falls back to the environment's `cloudformationRoleArn` in config.yaml)
+        #[arg(long)]
+        role_arn: Option<String>,
     },
 
     /// Rollback deployment
@@ -123,6 +251,56 @@ pub enum DeployCommands {
 
     /// Clean deployment artifacts
     Clean,
+
+    /// Create/review/execute/delete a CloudFormation change set instead of
+    /// deploying immediately
+    ChangeSet {
+        #[command(subcommand)]
+        action: ChangeSetAction,
+    },
+
+    /// Print the dependency-ordered wave/level grouping `deploy --all` would
+    /// use, without deploying anything
+    Plan,
+}
+
+#[derive(Subcommand, Clone)]
+pub enum ChangeSetAction {
+    /// Create a pending change set without applying it
+    Create {
+        /// Stack name
+        stack: String,
+
+        /// Change set name (defaults to a generated, timestamped name)
+        name: Option<String>,
+    },
+
+    /// Apply a previously-created change set
+    Execute {
+        /// Stack name
+        stack: String,
+
+        /// Change set name (defaults to the most recently created one)
+        name: Option<String>,
+    },
+
+    /// Describe a change set's resource-level additions/modifications/removals
+    Report {
+        /// Stack name
+        stack: String,
+
+        /// Change set name (defaults to the most recently created one)
+        name: Option<String>,
+    },
+
+    /// Discard a change set without applying it
+    Delete {
+        /// Stack name
+        stack: String,
+
+        /// Change set name (defaults to the most recently created one)
+        name: Option<String>,
+    },
 }
 
 #[derive(Subcommand, Clone)]
@@ -151,6 +329,51 @@ pub enum DevCommands {
         /// Build in release mode
         #[arg(long)]
         release: bool,
+
+        /// Cache the dependency layer separately from application code,
+        /// cargo-chef style: write a recipe + Dockerfile, then build only
+        /// dependencies from it so Docker can cache that layer independently.
+        #[arg(long)]
+        cached: bool,
+
+        /// Cargo-style build profile to record in the recipe (debug/release)
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Comma-separated feature list to record in the recipe
+        #[arg(long, value_delimiter = ',')]
+        features: Vec<String>,
+
+        /// Target triple to record in the recipe
+        #[arg(long)]
+        target: Option<String>,
+
+        /// Record a workspace-wide cook instead of a single-crate one
+        #[arg(long)]
+        workspace: bool,
+
+        /// Use only already-cached dependencies when cooking
+        #[arg(long)]
+        offline: bool,
+
+        /// Require the manifest lockfile to be up to date when cooking
+        #[arg(long)]
+        locked: bool,
+
+        /// Re-run the build whenever a watched component's files change
+        #[arg(long)]
+        watch: bool,
+
+        /// Cap on components built concurrently when building `all`
+        /// (defaults to the number of CPUs)
+        #[arg(short = 'j', long)]
+        jobs: Option<usize>,
+
+        /// Shuffle the build order of `all` with a seeded RNG to catch
+        /// hidden inter-component ordering assumptions; bare `--shuffle`
+        /// generates and prints a seed, `--shuffle SEED` reproduces one
+        #[arg(long, num_args = 0..=1)]
+        shuffle: Option<Option<u64>>,
     },
 
     /// Run tests
@@ -163,9 +386,33 @@ pub enum DevCommands {
         #[arg(long)]
         coverage: bool,
 
+        /// Fail if total line coverage drops below this percentage (implies `--coverage`)
+        #[arg(long)]
+        fail_under: Option<f64>,
+
         /// Run only specific test
         #[arg(long)]
         test: Option<String>,
+
+        /// Re-run affected tests whenever a watched component's files change
+        #[arg(long)]
+        watch: bool,
+
+        /// Cap on components tested concurrently when testing `all`
+        /// (defaults to the number of CPUs)
+        #[arg(short = 'j', long)]
+        jobs: Option<usize>,
+
+        /// Result format: human-readable, JSON (`test-report.json`), or
+        /// JUnit XML (`test-report.xml`) for CI ingestion
+        #[arg(long, value_enum, default_value = "pretty")]
+        reporter: crate::utils::test_report::ReporterKind,
+
+        /// Shuffle the test order of `all` with a seeded RNG to catch
+        /// hidden inter-component test-state leakage; bare `--shuffle`
+        /// generates and prints a seed, `--shuffle SEED` reproduces one
+        #[arg(long, num_args = 0..=1)]
+        shuffle: Option<Option<u64>>,
     },
 
     /// Run linting
@@ -177,6 +424,21 @@ pub enum DevCommands {
         /// Auto-fix issues
         #[arg(long)]
         fix: bool,
+
+        /// Re-lint affected components whenever their files change
+        #[arg(long)]
+        watch: bool,
+
+        /// Cap on components linted concurrently when linting `all`
+        /// (defaults to the number of CPUs)
+        #[arg(short = 'j', long)]
+        jobs: Option<usize>,
+
+        /// Shuffle the lint order of `all` with a seeded RNG to catch
+        /// hidden inter-component ordering assumptions; bare `--shuffle`
+        /// generates and prints a seed, `--shuffle SEED` reproduces one
+        #[arg(long, num_args = 0..=1)]
+        shuffle: Option<Option<u64>>,
     },
 
     /// Format code
@@ -191,6 +453,10 @@ pub enum DevCommands {
         /// Component to check
         #[arg(default_value = "all")]
         component: String,
+
+        /// Re-run type checking whenever a watched component's files change
+        #[arg(long)]
+        watch: bool,
     },
 
     /// Run pre-commit hooks
@@ -201,7 +467,15 @@ pub enum DevCommands {
 #[derive(Subcommand, Clone)]
 pub enum DbCommands {
     /// Run database migrations
-    Migrate,
+    Migrate {
+        /// Print the migration plan without applying anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Apply at most N pending migrations
+        #[arg(long)]
+        steps: Option<usize>,
+    },
 
     /// Seed test data
     Seed {
@@ -217,11 +491,18 @@ pub enum DbCommands {
         name: Option<String>,
     },
 
-    /// Restore database
+    /// Restore database from a backup file
     Restore {
         /// Backup name
         backup: String,
     },
+
+    /// Revert the last applied migration(s) using their .down.sql files
+    Rollback {
+        /// Number of migrations to revert
+        #[arg(long, default_value = "1")]
+        steps: usize,
+    },
 }
 
 #[derive(Subcommand, Clone)]
@@ -231,6 +512,11 @@ pub enum DepsCommands {
         /// Component
         #[arg(default_value = "all")]
         component: String,
+
+        /// Cap on components installed concurrently when installing `all`
+        /// (defaults to the number of CPUs)
+        #[arg(short = 'j', long)]
+        jobs: Option<usize>,
     },
 
     /// Update dependencies
@@ -238,6 +524,11 @@ pub enum DepsCommands {
         /// Component
         #[arg(default_value = "all")]
         component: String,
+
+        /// Cap on components updated concurrently when updating `all`
+        /// (defaults to the number of CPUs)
+        #[arg(short = 'j', long)]
+        jobs: Option<usize>,
     },
 
     /// Show outdated packages
@@ -248,7 +539,11 @@ pub enum DepsCommands {
     },
 
     /// Sync all dependencies
-    Sync,
+    Sync {
+        /// Cap on components synced concurrently (defaults to the number of CPUs)
+        #[arg(short = 'j', long)]
+        jobs: Option<usize>,
+    },
 }
 
 #[derive(Subcommand, Clone)]
@@ -366,4 +661,36 @@ pub enum ConfigCommands {
         /// Configuration key
         key: String,
     },
+
+    /// Check every environment's config for semantic problems serde can't
+    /// catch (bad account numbers, mismatched feature flags, ...), printing
+    /// all of them in one pass
+    Validate,
+
+    /// Manage command aliases
+    Alias {
+        #[command(subcommand)]
+        action: AliasCommands,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum AliasCommands {
+    /// List configured aliases
+    List,
+
+    /// Define or overwrite an alias
+    Set {
+        /// Alias name
+        name: String,
+
+        /// Expansion, e.g. "deploy deploy --all"
+        expansion: String,
+    },
+
+    /// Remove an alias
+    Remove {
+        /// Alias name
+        name: String,
+    },
 }
@@ -0,0 +1,63 @@
+use anyhow::Result;
+use std::time::Instant;
+use crate::cli::{Cli, CiCommands};
+use crate::config::EnvConfig;
+use crate::utils::{logger, executor::CommandExecutor, notifier::{Notifier, NotificationEvent}};
+
+pub async fn handle_ci(ci_cmd: CiCommands, env_config: &EnvConfig, cli: &Cli) -> Result<()> {
+    match ci_cmd {
+        CiCommands::Setup => setup_pipeline().await,
+        CiCommands::Validate => validate_pipeline().await,
+        CiCommands::Release { version } => create_release(version, env_config, cli).await,
+    }
+}
+
+async fn setup_pipeline() -> Result<()> {
+    logger::warning("CI pipeline setup requires project-specific scaffolding");
+    logger::info("Check the infrastructure/cdk directory for the expected pipeline stages");
+    Ok(())
+}
+
+async fn validate_pipeline() -> Result<()> {
+    let pipeline_file = std::path::Path::new(".github/workflows");
+    if pipeline_file.exists() {
+        logger::success("CI workflow directory found");
+    } else {
+        logger::warning("No .github/workflows directory found");
+    }
+    Ok(())
+}
+
+async fn create_release(version: Option<String>, env_config: &EnvConfig, cli: &Cli) -> Result<()> {
+    let version = version.unwrap_or_else(|| env!("CARGO_PKG_VERSION").to_string());
+    let notifier = Notifier::new(env_config.notifications.clone(), cli.dry_run);
+    let executor = CommandExecutor::with_policy(cli.dry_run, cli.verbose, cli.exec_policy());
+
+    logger::info(&format!("Creating release {}...", version));
+    let started = Instant::now();
+    let command_label = format!("ci release {}", version);
+
+    let tag_args = ["tag", "-a", &version, "-m", &format!("Release {}", version)];
+    let result = executor.execute("git", &tag_args, None).await;
+
+    match result {
+        Ok(_) => {
+            logger::success(&format!("Release {} tagged successfully!", version));
+            notifier
+                .notify(NotificationEvent::succeeded(&command_label, &env_config.deployment_name, started.elapsed()))
+                .await;
+            Ok(())
+        }
+        Err(e) => {
+            notifier
+                .notify(NotificationEvent::failed(
+                    &command_label,
+                    &env_config.deployment_name,
+                    started.elapsed(),
+                    e.to_string(),
+                ))
+                .await;
+            Err(e)
+        }
+    }
+}
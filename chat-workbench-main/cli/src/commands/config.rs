@@ -1,17 +1,101 @@
-use anyhow::Result;
-use crate::cli::{Cli, ConfigCommands};
-use crate::config::ProjectConfig;
-use crate::utils::logger;
+use anyhow::{Context, Result};
+use serde_yaml::Value;
+use crate::cli::{AliasCommands, Cli, ConfigCommands};
+use crate::config::{find_config_file, AliasValue, ProjectConfig};
+use crate::utils::{logger, prompts};
 
-pub async fn handle_config(action: Option<ConfigCommands>, project_config: &ProjectConfig, env: &str, _cli: &Cli) -> Result<()> {
+pub async fn handle_config(action: Option<ConfigCommands>, project_config: &ProjectConfig, env: &str, cli: &Cli) -> Result<()> {
     match action {
-        Some(ConfigCommands::Show) | None => show_config(project_config, env).await,
+        Some(ConfigCommands::Show) | None => show_config(project_config, env, cli).await,
         Some(ConfigCommands::Set { key, value }) => set_config(key, value, project_config, env).await,
-        Some(ConfigCommands::Get { key }) => get_config(key, project_config, env).await,
+        Some(ConfigCommands::Get { key }) => get_config(key, project_config, env, cli).await,
+        Some(ConfigCommands::Validate) => validate_config(project_config).await,
+        Some(ConfigCommands::Alias { action }) => handle_alias(action, project_config).await,
     }
 }
 
-async fn show_config(project_config: &ProjectConfig, env: &str) -> Result<()> {
+async fn validate_config(project_config: &ProjectConfig) -> Result<()> {
+    let errors = project_config.validate();
+
+    if errors.is_empty() {
+        logger::success("Configuration is valid.");
+        return Ok(());
+    }
+
+    for error in &errors {
+        let marker = if error.important { "error" } else { "warning" };
+        println!("[{}] {}.{}: {}", marker, error.env, error.field, error.message);
+    }
+
+    if errors.iter().any(|e| e.important) {
+        anyhow::bail!("Found {} configuration problem(s)", errors.len());
+    }
+
+    Ok(())
+}
+
+async fn handle_alias(action: AliasCommands, project_config: &ProjectConfig) -> Result<()> {
+    match action {
+        AliasCommands::List => {
+            if project_config.aliases.is_empty() {
+                println!("No aliases configured.");
+            } else {
+                println!("Configured aliases:");
+                for (name, value) in &project_config.aliases {
+                    println!("  {} = \"{}\"", name, value.as_command_line());
+                }
+            }
+            Ok(())
+        }
+        AliasCommands::Set { name, expansion } => {
+            if crate::cli::BUILTIN_COMMANDS.contains(&name.as_str()) {
+                anyhow::bail!("'{}' is a built-in command and cannot be used as an alias name", name);
+            }
+
+            let config_path = find_config_file()?;
+            let mut config = project_config.clone();
+            config.aliases.insert(name.clone(), AliasValue::Single(expansion.clone()));
+            save_config(&config_path, &config)?;
+
+            logger::success(&format!("Alias '{}' set to \"{}\"", name, expansion));
+            Ok(())
+        }
+        AliasCommands::Remove { name } => {
+            let config_path = find_config_file()?;
+            let mut config = project_config.clone();
+
+            if config.aliases.remove(&name).is_none() {
+                anyhow::bail!("Alias '{}' is not defined", name);
+            }
+
+            save_config(&config_path, &config)?;
+            logger::success(&format!("Alias '{}' removed", name));
+            Ok(())
+        }
+    }
+}
+
+fn save_config(config_path: &std::path::Path, config: &ProjectConfig) -> Result<()> {
+    let serialized = serde_yaml::to_string(config)
+        .context("Failed to serialize configuration")?;
+    std::fs::write(config_path, serialized)
+        .with_context(|| format!("Failed to write config file: {}", config_path.display()))?;
+    Ok(())
+}
+
+async fn show_config(project_config: &ProjectConfig, env: &str, cli: &Cli) -> Result<()> {
+    if cli.json_output() {
+        let env_config = project_config.get_env_config(env)?;
+        let json = serde_json::json!({
+            "current_env": env,
+            "default_env": project_config.get_default_env(),
+            "available_envs": project_config.get_available_environments(),
+            "env_config": env_config,
+        });
+        println!("{}", serde_json::to_string_pretty(&json).context("Failed to serialize configuration")?);
+        return Ok(());
+    }
+
     println!("Project Configuration");
     println!("====================");
     println!("Current Environment: {}", env);
@@ -44,13 +128,90 @@ async fn show_config(project_config: &ProjectConfig, env: &str) -> Result<()> {
     Ok(())
 }
 
-async fn set_config(_key: String, _value: String, _project_config: &ProjectConfig, _env: &str) -> Result<()> {
-    logger::warning("Configuration modification is not supported through CLI.");
-    logger::info("Please edit the config.yaml file directly to make changes.");
+/// Supported `config set`/`config get` dotted key paths, kept in one place
+/// so the two commands can't drift out of symmetry.
+const CONFIG_KEYS: &str = "env, default_env, deployment_name, account_number, region, app_name, log_level, aws_profile, vpc_config.vpc_id";
+
+async fn set_config(key: String, value: String, project_config: &ProjectConfig, env: &str) -> Result<()> {
+    // Validates the environment is actually configured before we touch the file.
+    project_config.get_env_config(env)?;
+
+    let parts: Vec<&str> = key.split('.').collect();
+    match parts.as_slice() {
+        ["env"] | ["default_env"] | ["deployment_name"] | ["account_number"] | ["region"]
+        | ["app_name"] | ["log_level"] | ["aws_profile"] | ["vpc_config", "vpc_id"] => {}
+        _ => anyhow::bail!("Unsupported configuration key: {}. Available keys: {}", key, CONFIG_KEYS),
+    }
+
+    let config_path = find_config_file()?;
+    let original_content = std::fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read config file: {}", config_path.display()))?;
+    let mut doc: Value = serde_yaml::from_str(&original_content)
+        .with_context(|| format!("Failed to parse {}", config_path.display()))?;
+
+    {
+        let root = doc.as_mapping_mut().context("config.yaml root is not a mapping")?;
+
+        match parts.as_slice() {
+            ["env"] | ["default_env"] => {
+                root.insert(Value::from("env"), Value::from(value.clone()));
+            }
+            _ => {
+                let env_map = root
+                    .get_mut(Value::from(env))
+                    .and_then(Value::as_mapping_mut)
+                    .with_context(|| format!("config.yaml has no '{}' environment configured", env))?;
+
+                match parts.as_slice() {
+                    ["vpc_config", "vpc_id"] => {
+                        let vpc_map = env_map
+                            .entry(Value::from("vpcConfig"))
+                            .or_insert_with(|| Value::Mapping(serde_yaml::Mapping::new()))
+                            .as_mapping_mut()
+                            .context("config.yaml 'vpcConfig' is not a mapping")?;
+                        vpc_map.insert(Value::from("vpcId"), Value::from(value.clone()));
+                    }
+                    [field] => {
+                        // Environment entries are stored under camelCase keys.
+                        let yaml_key = match *field {
+                            "deployment_name" => "deploymentName",
+                            "account_number" => "accountNumber",
+                            "app_name" => "appName",
+                            "log_level" => "logLevel",
+                            "aws_profile" => "awsProfile",
+                            other => other,
+                        };
+                        env_map.insert(Value::from(yaml_key), Value::from(value.clone()));
+                    }
+                    _ => unreachable!("key vocabulary validated above"),
+                }
+            }
+        }
+    }
+
+    // Re-deserialize into the typed struct before writing anything, so a bad
+    // edit (wrong type, invalid value) fails loudly instead of corrupting the
+    // file the next `cwb` invocation loads.
+    serde_yaml::from_value::<ProjectConfig>(doc.clone())
+        .context("Resulting configuration is invalid; refusing to write it")?;
+
+    if !prompts::confirm(&format!("Set '{}' = '{}' in {}?", key, value, config_path.display()), false)? {
+        logger::info("Aborted; config.yaml was not modified.");
+        return Ok(());
+    }
+
+    let serialized = serde_yaml::to_string(&doc).context("Failed to serialize configuration")?;
+    let tmp_path = config_path.with_extension("yaml.tmp");
+    std::fs::write(&tmp_path, &serialized)
+        .with_context(|| format!("Failed to write temporary config file: {}", tmp_path.display()))?;
+    std::fs::rename(&tmp_path, &config_path)
+        .with_context(|| format!("Failed to replace config file: {}", config_path.display()))?;
+
+    logger::success(&format!("Set '{}' = '{}'", key, value));
     Ok(())
 }
 
-async fn get_config(key: String, project_config: &ProjectConfig, env: &str) -> Result<()> {
+async fn get_config(key: String, project_config: &ProjectConfig, env: &str, cli: &Cli) -> Result<()> {
     let env_config = project_config.get_env_config(env)?;
 
     // Parse the key path
@@ -65,12 +226,17 @@ async fn get_config(key: String, project_config: &ProjectConfig, env: &str) -> R
         ["app_name"] => env_config.app_name.clone(),
         ["log_level"] => env_config.log_level.clone(),
         ["aws_profile"] => env_config.aws_profile.clone().unwrap_or_else(|| "default".to_string()),
+        ["vpc_config", "vpc_id"] => env_config.vpc_config.vpc_id.clone().unwrap_or_else(|| "create new".to_string()),
         _ => {
-            anyhow::bail!("Unsupported configuration key: {}. Available keys: env, default_env, deployment_name, account_number, region, app_name, log_level, aws_profile", key);
+            anyhow::bail!("Unsupported configuration key: {}. Available keys: {}", key, CONFIG_KEYS);
         }
     };
 
-    println!("{}", value);
+    if cli.json_output() {
+        println!("{}", serde_json::to_string_pretty(&serde_json::json!({ "key": key, "value": value }))?);
+    } else {
+        println!("{}", value);
+    }
 
     Ok(())
 }
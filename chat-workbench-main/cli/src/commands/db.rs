@@ -0,0 +1,373 @@
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use crate::cli::{Cli, DbCommands};
+use crate::config::EnvConfig;
+use crate::utils::{logger, executor::CommandExecutor};
+
+const MIGRATIONS_DIR: &str = "migrations";
+const TRACKING_TABLE: &str = "_cwb_migrations";
+
+/// A single discovered migration on disk.
+#[derive(Debug, Clone)]
+struct Migration {
+    version: String,
+    name: String,
+    up_path: PathBuf,
+    down_path: Option<PathBuf>,
+    checksum: String,
+}
+
+/// A row already recorded in `_cwb_migrations`.
+#[derive(Debug, Clone)]
+struct AppliedMigration {
+    version: String,
+    checksum: String,
+}
+
+pub async fn handle_db(db_cmd: DbCommands, env_config: &EnvConfig, cli: &Cli) -> Result<()> {
+    let executor = CommandExecutor::with_policy(cli.dry_run, cli.verbose, cli.exec_policy());
+
+    match db_cmd {
+        DbCommands::Migrate { dry_run, steps } => {
+            run_migrate(&executor, env_config, cli.dry_run || dry_run, steps).await
+        }
+        DbCommands::Seed { file } => run_seed(&executor, env_config, file).await,
+        DbCommands::Backup { name } => run_backup(&executor, env_config, name).await,
+        DbCommands::Restore { backup } => run_restore(&executor, env_config, &backup).await,
+        DbCommands::Rollback { steps } => run_rollback(&executor, env_config, steps).await,
+    }
+}
+
+fn database_url(env_config: &EnvConfig) -> Result<String> {
+    env_config
+        .database_url
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("No `databaseUrl` configured for this environment; set it in config.yaml"))
+}
+
+fn discover_migrations() -> Result<Vec<Migration>> {
+    discover_migrations_in(Path::new(MIGRATIONS_DIR))
+}
+
+fn discover_migrations_in(dir: &Path) -> Result<Vec<Migration>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut up_files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read migrations directory: {}", dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.ends_with(".up.sql"))
+                .unwrap_or(false)
+        })
+        .collect();
+
+    up_files.sort();
+
+    let mut migrations = Vec::new();
+    for up_path in up_files {
+        let file_name = up_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| anyhow::anyhow!("Invalid migration filename: {}", up_path.display()))?;
+
+        let stem = file_name.strip_suffix(".up.sql").unwrap();
+        let (version, name) = stem
+            .split_once('_')
+            .ok_or_else(|| anyhow::anyhow!("Migration filename must be NNNN_name.up.sql, got: {}", file_name))?;
+
+        // `version` is interpolated straight into tracking-table SQL below,
+        // so restrict it to a safe charset rather than trusting the filename.
+        if version.is_empty() || !version.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            anyhow::bail!(
+                "Invalid migration version '{}' in {}: must match [0-9A-Za-z_-]+",
+                version, file_name
+            );
+        }
+
+        let down_path = dir.join(format!("{}.down.sql", stem));
+        let down_path = down_path.exists().then_some(down_path);
+
+        let content = std::fs::read(&up_path)
+            .with_context(|| format!("Failed to read migration: {}", up_path.display()))?;
+        let checksum = format!("{:x}", Sha256::digest(&content));
+
+        migrations.push(Migration {
+            version: version.to_string(),
+            name: name.to_string(),
+            up_path,
+            down_path,
+            checksum,
+        });
+    }
+
+    Ok(migrations)
+}
+
+async fn ensure_tracking_table(executor: &CommandExecutor, url: &str) -> Result<()> {
+    let ddl = format!(
+        "CREATE TABLE IF NOT EXISTS {} (version text PRIMARY KEY, checksum text NOT NULL, applied_at timestamptz NOT NULL DEFAULT now())",
+        TRACKING_TABLE
+    );
+    executor.execute("psql", &[url, "-v", "ON_ERROR_STOP=1", "-c", &ddl], None).await?;
+    Ok(())
+}
+
+async fn fetch_applied(executor: &CommandExecutor, url: &str) -> Result<Vec<AppliedMigration>> {
+    let query = format!("SELECT version, checksum FROM {} ORDER BY version", TRACKING_TABLE);
+    let output = executor
+        .execute("psql", &[url, "-t", "-A", "-F", "|", "-c", &query], None)
+        .await?;
+
+    Ok(output
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| {
+            let (version, checksum) = line.split_once('|')?;
+            Some(AppliedMigration {
+                version: version.trim().to_string(),
+                checksum: checksum.trim().to_string(),
+            })
+        })
+        .collect())
+}
+
+async fn run_migrate(
+    executor: &CommandExecutor,
+    env_config: &EnvConfig,
+    dry_run: bool,
+    steps: Option<usize>,
+) -> Result<()> {
+    let url = database_url(env_config)?;
+    let migrations = discover_migrations()?;
+
+    if migrations.is_empty() {
+        logger::info("No migrations found in ./migrations");
+        return Ok(());
+    }
+
+    if !dry_run {
+        ensure_tracking_table(executor, &url).await?;
+    }
+
+    let applied = if dry_run {
+        Vec::new()
+    } else {
+        fetch_applied(executor, &url).await?
+    };
+
+    // Refuse to run if a previously-applied migration's file no longer
+    // matches what was recorded.
+    for record in &applied {
+        if let Some(migration) = migrations.iter().find(|m| m.version == record.version) {
+            if migration.checksum != record.checksum {
+                anyhow::bail!(
+                    "Checksum mismatch for migration {} ({}): applied checksum {} does not match file on disk {}. Refusing to run.",
+                    migration.version, migration.name, record.checksum, migration.checksum
+                );
+            }
+        }
+    }
+
+    let applied_versions: std::collections::HashSet<&str> =
+        applied.iter().map(|a| a.version.as_str()).collect();
+
+    let mut pending: Vec<&Migration> = migrations
+        .iter()
+        .filter(|m| !applied_versions.contains(m.version.as_str()))
+        .collect();
+
+    if let Some(n) = steps {
+        pending.truncate(n);
+    }
+
+    if pending.is_empty() {
+        logger::success("Database is up to date, no pending migrations");
+        return Ok(());
+    }
+
+    logger::info(&format!("{} pending migration(s):", pending.len()));
+    for migration in &pending {
+        println!("  {} - {}", migration.version, migration.name);
+    }
+
+    if dry_run {
+        logger::info("Dry run: no migrations were applied");
+        return Ok(());
+    }
+
+    for migration in pending {
+        logger::info(&format!("Applying {} - {}...", migration.version, migration.name));
+
+        // Run the up file and the tracking insert in a single transaction via `-1`.
+        let insert = format!(
+            "INSERT INTO {} (version, checksum) VALUES ('{}', '{}')",
+            TRACKING_TABLE, migration.version, migration.checksum
+        );
+
+        let up_path_str = migration.up_path.to_string_lossy().to_string();
+        executor
+            .execute(
+                "psql",
+                &[&url, "-v", "ON_ERROR_STOP=1", "-1", "-f", &up_path_str, "-c", &insert],
+                None,
+            )
+            .await
+            .with_context(|| format!("Migration {} failed, aborting run", migration.version))?;
+    }
+
+    logger::success("All pending migrations applied successfully!");
+    Ok(())
+}
+
+async fn run_rollback(executor: &CommandExecutor, env_config: &EnvConfig, steps: usize) -> Result<()> {
+    let url = database_url(env_config)?;
+    let migrations = discover_migrations()?;
+    let applied = fetch_applied(executor, &url).await?;
+
+    let mut to_revert: Vec<&AppliedMigration> = applied.iter().rev().take(steps).collect();
+    to_revert.reverse();
+
+    if to_revert.is_empty() {
+        logger::info("No applied migrations to roll back");
+        return Ok(());
+    }
+
+    for record in to_revert.into_iter().rev() {
+        let migration = migrations
+            .iter()
+            .find(|m| m.version == record.version)
+            .ok_or_else(|| anyhow::anyhow!("Migration {} is applied but its files are missing", record.version))?;
+
+        let down_path = migration
+            .down_path
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No .down.sql file for migration {}", migration.version))?;
+
+        logger::info(&format!("Reverting {} - {}...", migration.version, migration.name));
+
+        let delete = format!("DELETE FROM {} WHERE version = '{}'", TRACKING_TABLE, migration.version);
+        let down_path_str = down_path.to_string_lossy().to_string();
+
+        executor
+            .execute(
+                "psql",
+                &[&url, "-v", "ON_ERROR_STOP=1", "-1", "-f", &down_path_str, "-c", &delete],
+                None,
+            )
+            .await
+            .with_context(|| format!("Rollback of {} failed, aborting", migration.version))?;
+    }
+
+    logger::success("Rollback completed successfully!");
+    Ok(())
+}
+
+async fn run_seed(executor: &CommandExecutor, env_config: &EnvConfig, file: Option<String>) -> Result<()> {
+    let url = database_url(env_config)?;
+    let seed_file = file.unwrap_or_else(|| "seed.sql".to_string());
+
+    if !Path::new(&seed_file).exists() {
+        anyhow::bail!("Seed file not found: {}", seed_file);
+    }
+
+    logger::info(&format!("Seeding database from {}...", seed_file));
+    executor.execute("psql", &[&url, "-v", "ON_ERROR_STOP=1", "-f", &seed_file], None).await?;
+    logger::success("Database seeded successfully!");
+    Ok(())
+}
+
+async fn run_backup(executor: &CommandExecutor, env_config: &EnvConfig, name: Option<String>) -> Result<()> {
+    let url = database_url(env_config)?;
+    let backup_name = name.unwrap_or_else(|| format!("{}.dump", env_config.deployment_name));
+
+    logger::info(&format!("Backing up database to {}...", backup_name));
+    executor.execute("pg_dump", &[&url, "-Fc", "-f", &backup_name], None).await?;
+    logger::success("Database backed up successfully!");
+    Ok(())
+}
+
+async fn run_restore(executor: &CommandExecutor, env_config: &EnvConfig, backup: &str) -> Result<()> {
+    let url = database_url(env_config)?;
+
+    if !Path::new(backup).exists() {
+        anyhow::bail!("Backup file not found: {}", backup);
+    }
+
+    logger::info(&format!("Restoring database from {}...", backup));
+    executor.execute("pg_restore", &["-d", &url, "--clean", "--if-exists", backup], None).await?;
+    logger::success("Database restored successfully!");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_migration(dir: &Path, stem: &str, up_sql: &str, down_sql: Option<&str>) {
+        std::fs::write(dir.join(format!("{}.up.sql", stem)), up_sql).unwrap();
+        if let Some(down_sql) = down_sql {
+            std::fs::write(dir.join(format!("{}.down.sql", stem)), down_sql).unwrap();
+        }
+    }
+
+    #[test]
+    fn discover_migrations_in_missing_dir_returns_empty() {
+        let tmp = tempfile::tempdir().unwrap();
+        let migrations = discover_migrations_in(&tmp.path().join("does-not-exist")).unwrap();
+        assert!(migrations.is_empty());
+    }
+
+    #[test]
+    fn discover_migrations_in_orders_by_version_and_tracks_down_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_migration(tmp.path(), "0002_add_index", "CREATE INDEX ...;", None);
+        write_migration(tmp.path(), "0001_create_users", "CREATE TABLE users ...;", Some("DROP TABLE users;"));
+
+        let migrations = discover_migrations_in(tmp.path()).unwrap();
+
+        assert_eq!(migrations.len(), 2);
+        assert_eq!(migrations[0].version, "0001");
+        assert_eq!(migrations[0].name, "create_users");
+        assert!(migrations[0].down_path.is_some());
+        assert_eq!(migrations[1].version, "0002");
+        assert!(migrations[1].down_path.is_none());
+    }
+
+    #[test]
+    fn discover_migrations_in_same_content_has_same_checksum() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_migration(tmp.path(), "0001_create_users", "CREATE TABLE users ...;", None);
+
+        let first = discover_migrations_in(tmp.path()).unwrap();
+        let second = discover_migrations_in(tmp.path()).unwrap();
+
+        assert_eq!(first[0].checksum, second[0].checksum);
+        assert_eq!(first[0].checksum.len(), 64);
+    }
+
+    #[test]
+    fn discover_migrations_in_rejects_unsafe_version() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_migration(tmp.path(), "000'1_drop_table", "SELECT 1;", None);
+
+        let err = discover_migrations_in(tmp.path()).unwrap_err();
+
+        assert!(err.to_string().contains("Invalid migration version"));
+    }
+
+    #[test]
+    fn discover_migrations_in_rejects_missing_underscore() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_migration(tmp.path(), "0001", "SELECT 1;", None);
+
+        let err = discover_migrations_in(tmp.path()).unwrap_err();
+
+        assert!(err.to_string().contains("NNNN_name.up.sql"));
+    }
+}
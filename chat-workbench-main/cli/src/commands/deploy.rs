@@ -1,26 +1,33 @@
-use anyhow::Result;
-use crate::cli::{Cli, DeployCommands};
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use crate::cli::{Cli, ChangeSetAction, DeployCommands};
 use crate::config::{ProjectConfig, EnvConfig};
-use crate::utils::{logger, executor::CommandExecutor, prompts};
+use crate::utils::{
+    logger, backend, executor::{CommandExecutor, GraphNode}, notifier::{Notifier, NotificationEvent},
+    prompts, stack_graph, telemetry::{self, FailureReport},
+};
 
 pub async fn handle_deploy(deploy_cmd: DeployCommands, _project_config: &ProjectConfig, env_config: &EnvConfig, cli: &Cli) -> Result<()> {
-    let executor = CommandExecutor::new(cli.dry_run, cli.verbose);
+    let executor = CommandExecutor::with_backend(cli.dry_run, cli.verbose, cli.exec_policy(), backend::backend_for_env(env_config));
 
     match deploy_cmd {
-        DeployCommands::Deploy { stack, all } => {
-            deploy_stacks(stack, all, env_config, &executor, cli).await
+        DeployCommands::Deploy { stack, all, role_arn, concurrency, fail_fast } => {
+            deploy_stacks(stack, all, role_arn, concurrency, fail_fast, env_config, &executor, cli).await
         }
-        DeployCommands::Destroy { stack, all } => {
-            destroy_stacks(stack, all, env_config, &executor, cli).await
+        DeployCommands::Destroy { stack, all, role_arn, concurrency, fail_fast } => {
+            destroy_stacks(stack, all, role_arn, concurrency, fail_fast, env_config, &executor, cli).await
         }
         DeployCommands::Status => {
-            show_deployment_status(env_config, &executor).await
+            show_deployment_status(env_config, &executor, cli).await
         }
         DeployCommands::Diff { stack } => {
-            show_deployment_diff(stack, env_config, &executor).await
+            show_deployment_diff(stack, env_config, &executor, cli).await
         }
-        DeployCommands::Bootstrap { region } => {
-            bootstrap_environment(region, env_config, &executor).await
+        DeployCommands::Bootstrap { region, role_arn } => {
+            bootstrap_environment(region, role_arn, env_config, &executor).await
         }
         DeployCommands::Rollback { stack } => {
             rollback_deployment(stack, env_config, &executor, cli).await
@@ -28,18 +35,100 @@ pub async fn handle_deploy(deploy_cmd: DeployCommands, _project_config: &Project
         DeployCommands::Clean => {
             clean_deployment_artifacts(&executor).await
         }
+        DeployCommands::ChangeSet { action } => {
+            handle_change_set(action, env_config, &executor).await
+        }
+        DeployCommands::Plan => {
+            show_deploy_plan(env_config, &executor).await
+        }
     }
 }
 
+/// Resolve the CloudFormation service role to assume, preferring the
+/// `--role-arn` flag over the environment's `cloudformationRoleArn` in config.yaml.
+fn resolved_role_arn<'a>(role_arn: &'a Option<String>, env_config: &'a EnvConfig) -> Option<&'a str> {
+    role_arn
+        .as_deref()
+        .or(env_config.cloudformation_role_arn.as_deref())
+}
+
 async fn deploy_stacks(
     stack: Option<String>,
     all: bool,
+    role_arn: Option<String>,
+    concurrency: Option<usize>,
+    fail_fast: bool,
     env_config: &EnvConfig,
     executor: &CommandExecutor,
-    _cli: &Cli,
+    cli: &Cli,
 ) -> Result<()> {
-    logger::info(&format!("Deploying to environment: {} ({})", env_config.deployment_name, env_config.deployment_stage));
+    if !cli.json_output() {
+        logger::info(&format!("Deploying to environment: {} ({})", env_config.deployment_name, env_config.deployment_stage));
+    }
 
+    let role_arn = resolved_role_arn(&role_arn, env_config);
+    let notifier = Notifier::new(env_config.notifications.clone(), cli.dry_run);
+    let started = Instant::now();
+    let result = deploy_stacks_inner(stack, all, role_arn, concurrency, fail_fast, env_config, executor).await;
+
+    match &result {
+        Ok(label) => {
+            notifier
+                .notify(NotificationEvent::succeeded(label, &env_config.deployment_name, started.elapsed()))
+                .await;
+            if !cli.json_output() {
+                logger::success("Deployment completed successfully!");
+            }
+        }
+        Err(e) => {
+            notifier
+                .notify(NotificationEvent::failed("deploy", &env_config.deployment_name, started.elapsed(), e.to_string()))
+                .await;
+
+            // `execute_streaming` inherits stdio rather than capturing it, so the
+            // error string is the best stand-in for a stderr tail we have here.
+            let report = FailureReport::new("cdk deploy", None, &e.to_string(), &env_config.deployment_name);
+            if let Err(upload_err) = telemetry::offer_upload(&report, env_config.telemetry_config.as_ref(), executor).await {
+                logger::warning(&format!("Failed to upload failure report: {}", upload_err));
+            }
+        }
+    }
+
+    if cli.json_output() {
+        print_result_json(&result, started.elapsed());
+    }
+
+    result.map(|_| ())
+}
+
+/// Emit the `{ action, success, duration_secs, error? }` result object
+/// required by `--output json` for deploy/destroy operations.
+fn print_result_json(result: &Result<String>, duration: std::time::Duration) {
+    let json = match result {
+        Ok(action) => serde_json::json!({
+            "action": action,
+            "success": true,
+            "duration_secs": duration.as_secs_f64(),
+        }),
+        Err(e) => serde_json::json!({
+            "action": serde_json::Value::Null,
+            "success": false,
+            "duration_secs": duration.as_secs_f64(),
+            "error": e.to_string(),
+        }),
+    };
+    println!("{}", serde_json::to_string_pretty(&json).unwrap_or_default());
+}
+
+async fn deploy_stacks_inner(
+    stack: Option<String>,
+    all: bool,
+    role_arn: Option<&str>,
+    concurrency: Option<usize>,
+    fail_fast: bool,
+    env_config: &EnvConfig,
+    executor: &CommandExecutor,
+) -> Result<String> {
     // Get CDK directory - hardcoded based on project structure
     let cdk_dir = std::path::Path::new("./infrastructure/cdk");
 
@@ -51,11 +140,16 @@ async fn deploy_stacks(
     setup_cdk_environment(env_config)?;
 
     if all {
-        logger::info("Deploying all stacks...");
-        executor.execute_streaming("cdk", &["deploy", "--all", "--require-approval", "never"], Some(cdk_dir)).await?;
+        deploy_all_ordered(role_arn, concurrency, fail_fast, env_config, executor, cdk_dir).await
     } else if let Some(stack_name) = stack {
         logger::info(&format!("Deploying stack: {}", stack_name));
-        executor.execute_streaming("cdk", &["deploy", &stack_name, "--require-approval", "never"], Some(cdk_dir)).await?;
+        let mut args = vec!["deploy", stack_name.as_str(), "--require-approval", "never"];
+        if let Some(role_arn) = role_arn {
+            args.extend(["--role-arn", role_arn]);
+        }
+        executor.execute_streaming("cdk", &args, Some(cdk_dir)).await?;
+        snapshot_stack_templates(&env_config.deployment_name, std::slice::from_ref(&stack_name), cdk_dir, executor).await?;
+        Ok(format!("deploy {}", stack_name))
     } else {
         // Interactive stack selection
         let available_stacks = get_available_stacks(executor, cdk_dir).await?;
@@ -68,17 +162,147 @@ async fn deploy_stacks(
         let selected_stack = &available_stacks[selection];
 
         logger::info(&format!("Deploying stack: {}", selected_stack));
-        executor.execute_streaming("cdk", &["deploy", selected_stack, "--require-approval", "never"], Some(cdk_dir)).await?;
+        let mut args = vec!["deploy", selected_stack.as_str(), "--require-approval", "never"];
+        if let Some(role_arn) = role_arn {
+            args.extend(["--role-arn", role_arn]);
+        }
+        executor.execute_streaming("cdk", &args, Some(cdk_dir)).await?;
+        snapshot_stack_templates(&env_config.deployment_name, std::slice::from_ref(selected_stack), cdk_dir, executor).await?;
+        Ok(format!("deploy {}", selected_stack))
     }
+}
+
+/// Synthesize the app, build a stack dependency graph from the resulting
+/// `cdk.out/manifest.json`, and deploy it via `execute_dag` so independent
+/// stacks run concurrently (bounded by `concurrency`) while dependents wait
+/// on their dependencies, instead of just handing `--all` to the CDK.
+async fn deploy_all_ordered(
+    role_arn: Option<&str>,
+    concurrency: Option<usize>,
+    fail_fast: bool,
+    env_config: &EnvConfig,
+    executor: &CommandExecutor,
+    cdk_dir: &std::path::Path,
+) -> Result<String> {
+    executor.execute("cdk", &["synth", "--quiet"], Some(cdk_dir)).await?;
+    let stacks = stack_graph::parse_manifest(&cdk_dir.join("cdk.out"))?;
+    let waves = stack_graph::topological_waves(&stacks)?;
+    logger::info(&format!("Deploying {} stacks across {} wave(s)...", stacks.len(), waves.len()));
+
+    let nodes: Vec<GraphNode> = stacks
+        .iter()
+        .map(|stack| {
+            let mut args = vec!["deploy".to_string(), stack.name.clone(), "--require-approval".to_string(), "never".to_string()];
+            if let Some(role_arn) = role_arn {
+                args.push("--role-arn".to_string());
+                args.push(role_arn.to_string());
+            }
+            GraphNode::new(stack.name.clone(), "cdk".to_string(), args, Some(cdk_dir.to_path_buf()))
+                .depends_on(stack.depends_on.clone())
+        })
+        .collect();
+
+    let outcome = executor.execute_dag(nodes, concurrency, fail_fast).await?;
+    snapshot_stack_templates(&env_config.deployment_name, &outcome.completed, cdk_dir, executor).await?;
+
+    if let Some((id, err)) = &outcome.failed {
+        anyhow::bail!("Stack '{}' failed to deploy: {} (completed: {}, skipped: {})", id, err, outcome.completed.len(), outcome.skipped.len());
+    }
+
+    Ok(format!("deploy --all ({} stacks)", outcome.completed.len()))
+}
 
-    logger::success("Deployment completed successfully!");
+/// Directory where synthesized templates for `stack` are archived after each
+/// successful deploy, keyed by deployment so rollback can tell the last
+/// known-good template apart from the one that was just applied.
+fn stack_history_dir(deployment_name: &str, stack: &str) -> PathBuf {
+    PathBuf::from(".cwb").join("history").join(deployment_name).join(stack)
+}
+
+/// Copy each deployed stack's synthesized template out of `cdk.out` into its
+/// versioned history directory, so `rollback_deployment` has something to
+/// roll back to later. Best-effort: a stack whose template isn't on disk
+/// (e.g. `cdk deploy` skipped it because nothing changed) is simply skipped.
+///
+/// Alongside the template, also archive the stack's live parameters (as
+/// reported by CloudFormation right after the deploy that produced this
+/// template). CDK-synthesized templates commonly take asset S3 bucket/key
+/// parameters that `cdk deploy` supplies from its own asset manifest, and
+/// which the raw template alone doesn't carry — without them a later
+/// `aws cloudformation deploy` rollback would pick up the wrong (or no)
+/// asset location. Best-effort: if the describe-stacks call fails (e.g. in
+/// tests, or a stack with no parameters), rollback simply has none to replay.
+async fn snapshot_stack_templates(
+    deployment_name: &str,
+    stacks: &[String],
+    cdk_dir: &std::path::Path,
+    executor: &CommandExecutor,
+) -> Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    for stack in stacks {
+        let template_path = cdk_dir.join("cdk.out").join(format!("{}.template.json", stack));
+        if !template_path.exists() {
+            continue;
+        }
+
+        let history_dir = stack_history_dir(deployment_name, stack);
+        std::fs::create_dir_all(&history_dir)
+            .with_context(|| format!("Failed to create {}", history_dir.display()))?;
+
+        let dest = history_dir.join(format!("{}.json", timestamp));
+        std::fs::copy(&template_path, &dest)
+            .with_context(|| format!("Failed to archive template for stack '{}' to {}", stack, dest.display()))?;
+
+        if let Ok(parameters) = fetch_stack_parameters(executor, stack).await {
+            let params_dest = history_dir.join(format!("{}.params.json", timestamp));
+            let json = serde_json::to_string_pretty(&parameters).unwrap_or_default();
+            let _ = std::fs::write(&params_dest, json);
+        }
+    }
 
     Ok(())
 }
 
+/// Fetch the `ParameterKey`/`ParameterValue` pairs CloudFormation currently
+/// has on record for `stack`, as `aws cloudformation describe-stacks` reports
+/// them (this includes CDK asset parameters, not just user-supplied ones).
+async fn fetch_stack_parameters(executor: &CommandExecutor, stack: &str) -> Result<Vec<(String, String)>> {
+    let output = executor
+        .execute(
+            "aws",
+            &[
+                "cloudformation", "describe-stacks", "--stack-name", stack,
+                "--query", "Stacks[0].Parameters", "--output", "json",
+            ],
+            None,
+        )
+        .await?;
+
+    let value: serde_json::Value = serde_json::from_str(&output).context("Failed to parse describe-stacks output")?;
+    let params = value
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|p| {
+            let key = p.get("ParameterKey")?.as_str()?.to_string();
+            let value = p.get("ParameterValue")?.as_str()?.to_string();
+            Some((key, value))
+        })
+        .collect();
+
+    Ok(params)
+}
+
 async fn destroy_stacks(
     stack: Option<String>,
     all: bool,
+    role_arn: Option<String>,
+    concurrency: Option<usize>,
+    fail_fast: bool,
     env_config: &EnvConfig,
     executor: &CommandExecutor,
     cli: &Cli,
@@ -93,44 +317,113 @@ async fn destroy_stacks(
         }
     }
 
+    let role_arn = resolved_role_arn(&role_arn, env_config);
     let cdk_dir = std::path::Path::new("./infrastructure/cdk");
     setup_cdk_environment(env_config)?;
 
-    if all {
-        logger::info("Destroying all stacks...");
-        executor.execute_streaming("cdk", &["destroy", "--all", "--force"], Some(cdk_dir)).await?;
-    } else if let Some(stack_name) = stack {
-        logger::info(&format!("Destroying stack: {}", stack_name));
-        executor.execute_streaming("cdk", &["destroy", &stack_name, "--force"], Some(cdk_dir)).await?;
-    } else {
-        let available_stacks = get_available_stacks(executor, cdk_dir).await?;
-        if available_stacks.is_empty() {
-            anyhow::bail!("No CDK stacks found");
+    let started = Instant::now();
+    let result: Result<String> = async {
+        if all {
+            destroy_all_ordered(role_arn, concurrency, fail_fast, executor, cdk_dir).await
+        } else if let Some(stack_name) = stack {
+            logger::info(&format!("Destroying stack: {}", stack_name));
+            let mut args = vec!["destroy", stack_name.as_str(), "--force"];
+            if let Some(role_arn) = role_arn {
+                args.extend(["--role-arn", role_arn]);
+            }
+            executor.execute_streaming("cdk", &args, Some(cdk_dir)).await?;
+            Ok(format!("destroy {}", stack_name))
+        } else {
+            let available_stacks = get_available_stacks(executor, cdk_dir).await?;
+            if available_stacks.is_empty() {
+                anyhow::bail!("No CDK stacks found");
+            }
+
+            let stack_names: Vec<&str> = available_stacks.iter().map(|s| s.as_str()).collect();
+            let selection = prompts::select_option("Select stack to destroy", &stack_names)?;
+            let selected_stack = &available_stacks[selection];
+
+            logger::info(&format!("Destroying stack: {}", selected_stack));
+            let mut args = vec!["destroy", selected_stack.as_str(), "--force"];
+            if let Some(role_arn) = role_arn {
+                args.extend(["--role-arn", role_arn]);
+            }
+            executor.execute_streaming("cdk", &args, Some(cdk_dir)).await?;
+            Ok(format!("destroy {}", selected_stack))
         }
+    }
+    .await;
 
-        let stack_names: Vec<&str> = available_stacks.iter().map(|s| s.as_str()).collect();
-        let selection = prompts::select_option("Select stack to destroy", &stack_names)?;
-        let selected_stack = &available_stacks[selection];
-
-        logger::info(&format!("Destroying stack: {}", selected_stack));
-        executor.execute_streaming("cdk", &["destroy", selected_stack, "--force"], Some(cdk_dir)).await?;
+    if !cli.json_output() {
+        if result.is_ok() {
+            logger::success("Destroy operation completed successfully!");
+        }
+    } else {
+        print_result_json(&result, started.elapsed());
     }
 
-    logger::success("Destroy operation completed successfully!");
+    result.map(|_| ())
+}
 
-    Ok(())
+/// Mirror image of `deploy_all_ordered`: walk the same dependency graph in
+/// reverse, so a stack is only destroyed once every stack that depended on
+/// it is already gone.
+async fn destroy_all_ordered(
+    role_arn: Option<&str>,
+    concurrency: Option<usize>,
+    fail_fast: bool,
+    executor: &CommandExecutor,
+    cdk_dir: &std::path::Path,
+) -> Result<String> {
+    // Synth first, same as `deploy_all_ordered`: reading a stale `cdk.out`
+    // here would compute dependency waves from infra code that no longer
+    // matches what's deployed, risking the wrong destroy order.
+    executor.execute("cdk", &["synth", "--quiet"], Some(cdk_dir)).await?;
+    let stacks = stack_graph::parse_manifest(&cdk_dir.join("cdk.out"))?;
+    let reversed = stack_graph::reversed(&stacks);
+    let waves = stack_graph::topological_waves(&reversed)?;
+    logger::info(&format!("Destroying {} stacks across {} wave(s)...", reversed.len(), waves.len()));
+
+    let nodes: Vec<GraphNode> = reversed
+        .iter()
+        .map(|stack| {
+            let mut args = vec!["destroy".to_string(), stack.name.clone(), "--force".to_string()];
+            if let Some(role_arn) = role_arn {
+                args.push("--role-arn".to_string());
+                args.push(role_arn.to_string());
+            }
+            GraphNode::new(stack.name.clone(), "cdk".to_string(), args, Some(cdk_dir.to_path_buf()))
+                .depends_on(stack.depends_on.clone())
+        })
+        .collect();
+
+    let outcome = executor.execute_dag(nodes, concurrency, fail_fast).await?;
+
+    if let Some((id, err)) = &outcome.failed {
+        anyhow::bail!("Stack '{}' failed to destroy: {} (completed: {}, skipped: {})", id, err, outcome.completed.len(), outcome.skipped.len());
+    }
+
+    Ok(format!("destroy --all ({} stacks)", outcome.completed.len()))
 }
 
 async fn show_deployment_status(
     env_config: &EnvConfig,
     executor: &CommandExecutor,
+    cli: &Cli,
 ) -> Result<()> {
-    logger::info(&format!("Deployment status for environment: {}", env_config.deployment_name));
-
     let cdk_dir = std::path::Path::new("./infrastructure/cdk");
     setup_cdk_environment(env_config)?;
 
-    executor.execute_streaming("cdk", &["list"], Some(cdk_dir)).await?;
+    let stacks = get_available_stacks(executor, cdk_dir).await?;
+
+    if cli.json_output() {
+        println!("{}", serde_json::to_string_pretty(&stacks).context("Failed to serialize stack list")?);
+    } else {
+        logger::info(&format!("Deployment status for environment: {}", env_config.deployment_name));
+        for stack in &stacks {
+            println!("{}", stack);
+        }
+    }
 
     Ok(())
 }
@@ -139,16 +432,46 @@ async fn show_deployment_diff(
     stack: Option<String>,
     env_config: &EnvConfig,
     executor: &CommandExecutor,
+    cli: &Cli,
 ) -> Result<()> {
-    logger::info(&format!("Showing deployment diff for environment: {}", env_config.deployment_name));
-
     let cdk_dir = std::path::Path::new("./infrastructure/cdk");
     setup_cdk_environment(env_config)?;
 
-    if let Some(stack_name) = stack {
-        executor.execute_streaming("cdk", &["diff", &stack_name], Some(cdk_dir)).await?;
+    let stacks = match &stack {
+        Some(stack_name) => vec![stack_name.clone()],
+        None => get_available_stacks(executor, cdk_dir).await?,
+    };
+
+    if cli.json_output() {
+        let mut reports = Vec::new();
+        for stack_name in &stacks {
+            let diff = executor.execute("cdk", &["diff", stack_name], Some(cdk_dir)).await?;
+            reports.push(serde_json::json!({ "stack": stack_name, "diff": diff }));
+        }
+        println!("{}", serde_json::to_string_pretty(&reports).context("Failed to serialize diff report")?);
     } else {
-        executor.execute_streaming("cdk", &["diff"], Some(cdk_dir)).await?;
+        logger::info(&format!("Showing deployment diff for environment: {}", env_config.deployment_name));
+        if let Some(stack_name) = stack {
+            executor.execute_streaming("cdk", &["diff", &stack_name], Some(cdk_dir)).await?;
+        } else {
+            executor.execute_streaming("cdk", &["diff"], Some(cdk_dir)).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn show_deploy_plan(env_config: &EnvConfig, executor: &CommandExecutor) -> Result<()> {
+    let cdk_dir = std::path::Path::new("./infrastructure/cdk");
+    setup_cdk_environment(env_config)?;
+
+    executor.execute("cdk", &["synth", "--quiet"], Some(cdk_dir)).await?;
+    let stacks = stack_graph::parse_manifest(&cdk_dir.join("cdk.out"))?;
+    let waves = stack_graph::topological_waves(&stacks)?;
+
+    logger::info(&format!("Deploy plan for environment: {} ({} stacks, {} wave(s))", env_config.deployment_name, stacks.len(), waves.len()));
+    for (i, wave) in waves.iter().enumerate() {
+        println!("Wave {}: {}", i + 1, wave.join(", "));
     }
 
     Ok(())
@@ -156,10 +479,12 @@ async fn show_deployment_diff(
 
 async fn bootstrap_environment(
     region: Option<String>,
+    role_arn: Option<String>,
     env_config: &EnvConfig,
     executor: &CommandExecutor,
 ) -> Result<()> {
     let aws_region = region.unwrap_or_else(|| env_config.region.clone());
+    let role_arn = resolved_role_arn(&role_arn, env_config);
 
     logger::info(&format!("Bootstrapping CDK in region: {} for account: {}", aws_region, env_config.account_number));
 
@@ -169,6 +494,9 @@ async fn bootstrap_environment(
     let mut args = vec!["bootstrap"];
     args.push("--region");
     args.push(&aws_region);
+    if let Some(role_arn) = role_arn {
+        args.extend(["--role-arn", role_arn]);
+    }
 
     executor.execute_streaming("cdk", &args, Some(cdk_dir)).await?;
 
@@ -180,20 +508,87 @@ async fn bootstrap_environment(
 async fn rollback_deployment(
     stack: String,
     env_config: &EnvConfig,
-    _executor: &CommandExecutor,
-    _cli: &Cli,
+    executor: &CommandExecutor,
+    cli: &Cli,
 ) -> Result<()> {
-    logger::warning("Rollback functionality requires custom implementation based on your deployment strategy");
-    logger::info(&format!("Would rollback stack '{}' in environment '{}'", stack, env_config.deployment_name));
+    if !cli.force {
+        let confirmed = prompts::confirm_destructive("rollback", &format!("stack '{}' in {}", stack, env_config.deployment_name))?;
+        if !confirmed {
+            logger::info("Rollback cancelled");
+            return Ok(());
+        }
+    }
 
-    // This would typically involve:
-    // 1. Getting the previous deployment version/state
-    // 2. Re-deploying with the previous configuration
-    // 3. Updating any external dependencies
+    let history_dir = stack_history_dir(&env_config.deployment_name, &stack);
+    let previous = select_rollback_target(&history_dir)
+        .with_context(|| format!("No prior deployed version of stack '{}' found in {}", stack, history_dir.display()))?;
+
+    logger::info(&format!(
+        "Rolling back stack '{}' to {}",
+        stack,
+        previous.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default()
+    ));
+
+    let template_path = previous.to_string_lossy().to_string();
+    let parameter_overrides = load_parameter_overrides(&previous)?;
+    if parameter_overrides.is_none() {
+        logger::warning(&format!(
+            "No archived parameters found alongside {}; rolling back without --parameter-overrides \
+             may fail or misplace CDK asset references",
+            template_path
+        ));
+    }
+
+    let mut args = vec!["cloudformation", "deploy", "--template-file", &template_path, "--stack-name", &stack];
+    if let Some(overrides) = &parameter_overrides {
+        args.extend(["--parameter-overrides", overrides]);
+    }
+    executor.execute_streaming("aws", &args, None).await?;
+
+    logger::success(&format!("Rolled back stack '{}'", stack));
 
     Ok(())
 }
 
+/// Pick the last-known-good template snapshot to roll back to: the second
+/// most recent `.json` snapshot in `history_dir` (the most recent one is the
+/// version currently deployed, not the one to roll back to). Filenames are
+/// unix-second timestamps, so lexicographic sort is chronological.
+fn select_rollback_target(history_dir: &std::path::Path) -> Result<PathBuf> {
+    let mut snapshots: Vec<PathBuf> = std::fs::read_dir(history_dir)
+        .context("no deployment history directory")?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("json"))
+        .filter(|path| !path.to_string_lossy().ends_with(".params.json"))
+        .collect();
+
+    if snapshots.len() < 2 {
+        anyhow::bail!("nothing to roll back to");
+    }
+
+    snapshots.sort();
+    Ok(snapshots.swap_remove(snapshots.len() - 2))
+}
+
+/// Load the `--parameter-overrides` string archived alongside a template
+/// snapshot by `snapshot_stack_templates`, if any.
+fn load_parameter_overrides(template_path: &std::path::Path) -> Result<Option<String>> {
+    let params_path = template_path.with_extension("").with_extension("params.json");
+    if !params_path.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(&params_path)
+        .with_context(|| format!("Failed to read archived parameters at {}", params_path.display()))?;
+    let params: Vec<(String, String)> = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse archived parameters at {}", params_path.display()))?;
+
+    Ok((!params.is_empty()).then(|| {
+        params.iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join(",")
+    }))
+}
+
 async fn clean_deployment_artifacts(executor: &CommandExecutor) -> Result<()> {
     logger::info("Cleaning deployment artifacts...");
 
@@ -235,3 +630,191 @@ fn setup_cdk_environment(env_config: &EnvConfig) -> Result<()> {
 
     Ok(())
 }
+
+/// Per-stack history of change sets this tool has created, so `Execute`,
+/// `Report`, and `Delete` can default to "the one we just made" without the
+/// caller re-typing its generated name. Oldest first; the last entry for a
+/// stack is its most recent change set.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ChangeSetHistory {
+    by_stack: HashMap<String, Vec<String>>,
+}
+
+fn changeset_history_path() -> PathBuf {
+    PathBuf::from(".cwb").join("changesets.json")
+}
+
+fn load_changeset_history() -> ChangeSetHistory {
+    let path = changeset_history_path();
+    std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_changeset_history(history: &ChangeSetHistory) -> Result<()> {
+    let path = changeset_history_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let json = serde_json::to_string_pretty(history).context("Failed to serialize change set history")?;
+    std::fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn record_changeset(stack: &str, name: &str) -> Result<()> {
+    let mut history = load_changeset_history();
+    history.by_stack.entry(stack.to_string()).or_default().push(name.to_string());
+    save_changeset_history(&history)
+}
+
+fn remove_changeset(stack: &str, name: &str) -> Result<()> {
+    let mut history = load_changeset_history();
+    if let Some(names) = history.by_stack.get_mut(stack) {
+        names.retain(|n| n != name);
+    }
+    save_changeset_history(&history)
+}
+
+fn resolve_changeset_name(stack: &str, name: Option<String>) -> Result<String> {
+    if let Some(name) = name {
+        return Ok(name);
+    }
+    load_changeset_history()
+        .by_stack
+        .get(stack)
+        .and_then(|names| names.last().cloned())
+        .ok_or_else(|| anyhow::anyhow!("No change set on record for stack '{}'; pass one by name", stack))
+}
+
+fn generated_changeset_name() -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format!("cwb-{}", timestamp)
+}
+
+async fn handle_change_set(action: ChangeSetAction, env_config: &EnvConfig, executor: &CommandExecutor) -> Result<()> {
+    let cdk_dir = std::path::Path::new("./infrastructure/cdk");
+    setup_cdk_environment(env_config)?;
+
+    match action {
+        ChangeSetAction::Create { stack, name } => {
+            let name = name.unwrap_or_else(generated_changeset_name);
+            logger::info(&format!("Creating change set '{}' for stack: {}", name, stack));
+            executor
+                .execute_streaming("cdk", &["deploy", &stack, "--no-execute", "--change-set-name", &name], Some(cdk_dir))
+                .await?;
+            record_changeset(&stack, &name)?;
+            logger::success(&format!("Change set '{}' created for stack '{}'", name, stack));
+            Ok(())
+        }
+        ChangeSetAction::Report { stack, name } => {
+            let name = resolve_changeset_name(&stack, name)?;
+            logger::info(&format!("Describing change set '{}' for stack: {}", name, stack));
+            let output = executor
+                .execute("aws", &["cloudformation", "describe-change-set", "--stack-name", &stack, "--change-set-name", &name], None)
+                .await?;
+            print_changeset_report(&output)
+        }
+        ChangeSetAction::Execute { stack, name } => {
+            let name = resolve_changeset_name(&stack, name)?;
+            logger::info(&format!("Executing change set '{}' for stack: {}", name, stack));
+            executor
+                .execute_streaming("aws", &["cloudformation", "execute-change-set", "--stack-name", &stack, "--change-set-name", &name], None)
+                .await?;
+            remove_changeset(&stack, &name)?;
+            logger::success(&format!("Change set '{}' executed for stack '{}'", name, stack));
+            Ok(())
+        }
+        ChangeSetAction::Delete { stack, name } => {
+            let name = resolve_changeset_name(&stack, name)?;
+            executor
+                .execute("aws", &["cloudformation", "delete-change-set", "--stack-name", &stack, "--change-set-name", &name], None)
+                .await?;
+            remove_changeset(&stack, &name)?;
+            logger::success(&format!("Change set '{}' deleted for stack '{}'", name, stack));
+            Ok(())
+        }
+    }
+}
+
+/// Print the resource-level additions/modifications/removals from an
+/// `aws cloudformation describe-change-set` JSON response.
+fn print_changeset_report(raw: &str) -> Result<()> {
+    let value: serde_json::Value = serde_json::from_str(raw).context("Failed to parse change set description")?;
+    let changes = value.get("Changes").and_then(|c| c.as_array()).cloned().unwrap_or_default();
+
+    if changes.is_empty() {
+        logger::info("No resource changes in this change set");
+        return Ok(());
+    }
+
+    for change in &changes {
+        let resource_change = &change["ResourceChange"];
+        let action = resource_change.get("Action").and_then(|v| v.as_str()).unwrap_or("?");
+        let logical_id = resource_change.get("LogicalResourceId").and_then(|v| v.as_str()).unwrap_or("?");
+        let resource_type = resource_change.get("ResourceType").and_then(|v| v.as_str()).unwrap_or("?");
+        println!("  {:<8} {} ({})", action, logical_id, resource_type);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_rollback_target_picks_second_most_recent_snapshot() {
+        let tmp = tempfile::tempdir().unwrap();
+        for ts in ["100", "200", "300"] {
+            std::fs::write(tmp.path().join(format!("{}.json", ts)), "{}").unwrap();
+        }
+
+        let previous = select_rollback_target(tmp.path()).unwrap();
+
+        assert_eq!(previous.file_name().unwrap().to_str().unwrap(), "200.json");
+    }
+
+    #[test]
+    fn select_rollback_target_fails_with_fewer_than_two_snapshots() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("100.json"), "{}").unwrap();
+
+        assert!(select_rollback_target(tmp.path()).is_err());
+    }
+
+    #[test]
+    fn select_rollback_target_fails_when_history_dir_is_missing() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert!(select_rollback_target(&tmp.path().join("does-not-exist")).is_err());
+    }
+
+    #[test]
+    fn load_parameter_overrides_builds_key_value_pairs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let template = tmp.path().join("200.json");
+        std::fs::write(&template, "{}").unwrap();
+        std::fs::write(
+            tmp.path().join("200.params.json"),
+            r#"[["AssetBucket", "my-bucket"], ["AssetKey", "abc123"]]"#,
+        ).unwrap();
+
+        let overrides = load_parameter_overrides(&template).unwrap();
+
+        assert_eq!(overrides, Some("AssetBucket=my-bucket,AssetKey=abc123".to_string()));
+    }
+
+    #[test]
+    fn load_parameter_overrides_is_none_without_archived_params() {
+        let tmp = tempfile::tempdir().unwrap();
+        let template = tmp.path().join("200.json");
+        std::fs::write(&template, "{}").unwrap();
+
+        let overrides = load_parameter_overrides(&template).unwrap();
+
+        assert_eq!(overrides, None);
+    }
+}
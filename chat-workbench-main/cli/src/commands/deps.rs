@@ -1,55 +1,132 @@
 use anyhow::Result;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use crate::cli::{Cli, DepsCommands};
 use crate::config::ComponentConfig;
-use crate::utils::{logger, executor::CommandExecutor};
+use crate::utils::{logger, executor::{CommandExecutor, StreamingCommand}};
 
-pub async fn handle_deps(deps_cmd: DepsCommands, components: &HashMap<String, ComponentConfig>, cli: &Cli) -> Result<()> {
-    let executor = CommandExecutor::new(cli.dry_run, cli.verbose);
+pub async fn handle_deps(
+    deps_cmd: DepsCommands,
+    components: &HashMap<String, ComponentConfig>,
+    max_parallel: Option<usize>,
+    cli: &Cli,
+) -> Result<()> {
+    let executor = CommandExecutor::with_policy(cli.dry_run, cli.verbose, cli.exec_policy());
 
     match deps_cmd {
-        DepsCommands::Install { component } => {
-            install_dependencies(component, components, &executor).await
+        DepsCommands::Install { component, jobs } => {
+            install_dependencies(component, jobs.or(max_parallel), components, &executor).await
         }
-        DepsCommands::Update { component } => {
-            update_dependencies(component, components, &executor).await
+        DepsCommands::Update { component, jobs } => {
+            update_dependencies(component, jobs.or(max_parallel), components, &executor).await
         }
         DepsCommands::Outdated { component } => {
             show_outdated_packages(component, components, &executor).await
         }
-        DepsCommands::Sync => {
-            sync_all_dependencies(components, &executor).await
+        DepsCommands::Sync { jobs } => {
+            sync_all_dependencies(jobs.or(max_parallel), components, &executor).await
         }
     }
 }
 
-async fn install_dependencies(
-    component: String,
+/// Run `command_for`'s command for every component with a recognized package
+/// manager, concurrently (bounded by `jobs`, defaulting to the CPU count),
+/// streaming each one's output with a `[component]` prefix. One component
+/// failing never stops the others; every outcome is reported in one summary.
+async fn run_deps_parallel(
+    action: &str,
+    jobs: Option<usize>,
     components: &HashMap<String, ComponentConfig>,
     executor: &CommandExecutor,
+    command_for: impl Fn(&ComponentConfig) -> Option<(String, Vec<String>)>,
 ) -> Result<()> {
-    if component == "all" {
-        logger::info("Installing dependencies for all components...");
-
-        // Install backend dependencies (Python/uv)
-        if components.contains_key("backend") {
-            logger::info("Installing backend dependencies with uv...");
-            executor.execute_streaming("uv", &["sync"], None).await?;
+    let mut names: Vec<&String> = components.keys().collect();
+    names.sort();
+
+    let mut commands = Vec::new();
+    for name in names {
+        let comp_config = &components[name];
+        match command_for(comp_config) {
+            Some((cmd, args)) => commands.push(StreamingCommand::new(
+                comp_config.name.clone(),
+                cmd,
+                args,
+                Some(PathBuf::from(&comp_config.path)),
+            )),
+            None => logger::warning(&format!(
+                "Unknown package manager for component '{}': {}",
+                comp_config.name, comp_config.package_manager
+            )),
         }
+    }
 
-        // Install frontend dependencies (Node.js/npm)
-        if components.contains_key("frontend") {
-            logger::info("Installing frontend dependencies...");
-            executor.execute_streaming("npm", &["install"], Some(&std::path::Path::new("ui"))).await?;
-        }
+    if commands.is_empty() {
+        logger::warning("No components with a supported package manager found");
+        return Ok(());
+    }
+
+    let outputs = executor.execute_parallel_streaming(commands, jobs).await?;
 
-        // Install infrastructure dependencies (CDK/npm)
-        if components.contains_key("infrastructure") {
-            logger::info("Installing infrastructure dependencies...");
-            executor.execute_streaming("npm", &["install"], Some(&std::path::Path::new("infrastructure/cdk"))).await?;
+    let failed: Vec<_> = outputs.iter().filter(|o| !o.success).collect();
+    logger::info(&format!(
+        "{} summary: {} succeeded, {} failed",
+        action, outputs.len() - failed.len(), failed.len()
+    ));
+
+    if failed.is_empty() {
+        logger::success(&format!("All dependencies {} successfully!", action));
+        Ok(())
+    } else {
+        for output in &failed {
+            logger::error(&format!("{} failed for '{}' (exit code {:?})", action, output.label, output.exit_code));
         }
+        anyhow::bail!(
+            "{} failed for: {}",
+            action,
+            failed.iter().map(|o| o.label.clone()).collect::<Vec<_>>().join(", ")
+        );
+    }
+}
+
+fn install_command_parts(comp_config: &ComponentConfig) -> Option<(String, Vec<String>)> {
+    match comp_config.package_manager.as_str() {
+        "uv" => Some(("uv".to_string(), vec!["sync".to_string()])),
+        "npm" => Some(("npm".to_string(), vec!["install".to_string()])),
+        "yarn" => Some(("yarn".to_string(), vec!["install".to_string()])),
+        "pnpm" => Some(("pnpm".to_string(), vec!["install".to_string()])),
+        _ => None,
+    }
+}
+
+fn update_command_parts(comp_config: &ComponentConfig) -> Option<(String, Vec<String>)> {
+    match comp_config.package_manager.as_str() {
+        "uv" => Some(("uv".to_string(), vec!["sync".to_string(), "--upgrade".to_string()])),
+        "npm" => Some(("npm".to_string(), vec!["update".to_string()])),
+        "yarn" => Some(("yarn".to_string(), vec!["upgrade".to_string()])),
+        "pnpm" => Some(("pnpm".to_string(), vec!["update".to_string()])),
+        _ => None,
+    }
+}
 
-        logger::success("All dependencies installed successfully!");
+fn sync_command_parts(comp_config: &ComponentConfig) -> Option<(String, Vec<String>)> {
+    match comp_config.package_manager.as_str() {
+        "uv" => Some(("uv".to_string(), vec!["sync".to_string()])),
+        "npm" => Some(("npm".to_string(), vec!["ci".to_string()])),
+        "yarn" => Some(("yarn".to_string(), vec!["install".to_string(), "--frozen-lockfile".to_string()])),
+        "pnpm" => Some(("pnpm".to_string(), vec!["install".to_string(), "--frozen-lockfile".to_string()])),
+        _ => None,
+    }
+}
+
+async fn install_dependencies(
+    component: String,
+    jobs: Option<usize>,
+    components: &HashMap<String, ComponentConfig>,
+    executor: &CommandExecutor,
+) -> Result<()> {
+    if component == "all" {
+        logger::info("Installing dependencies for all components...");
+        run_deps_parallel("install", jobs, components, executor, install_command_parts).await
     } else {
         let comp_config = components.get(&component)
             .ok_or_else(|| anyhow::anyhow!("Component '{}' not found", component))?;
@@ -77,39 +154,19 @@ async fn install_dependencies(
         }
 
         logger::success(&format!("{} dependencies installed successfully!", component));
+        Ok(())
     }
-
-    Ok(())
 }
 
 async fn update_dependencies(
     component: String,
+    jobs: Option<usize>,
     components: &HashMap<String, ComponentConfig>,
     executor: &CommandExecutor,
 ) -> Result<()> {
     if component == "all" {
         logger::info("Updating dependencies for all components...");
-
-        // Update backend dependencies
-        if components.contains_key("backend") {
-            logger::info("Updating backend dependencies...");
-            executor.execute_streaming("uv", &["lock", "--upgrade"], None).await?;
-            executor.execute_streaming("uv", &["sync"], None).await?;
-        }
-
-        // Update frontend dependencies
-        if components.contains_key("frontend") {
-            logger::info("Updating frontend dependencies...");
-            executor.execute_streaming("npm", &["update"], Some(&std::path::Path::new("ui"))).await?;
-        }
-
-        // Update infrastructure dependencies
-        if components.contains_key("infrastructure") {
-            logger::info("Updating infrastructure dependencies...");
-            executor.execute_streaming("npm", &["update"], Some(&std::path::Path::new("infrastructure/cdk"))).await?;
-        }
-
-        logger::success("All dependencies updated successfully!");
+        run_deps_parallel("update", jobs, components, executor, update_command_parts).await
     } else {
         let comp_config = components.get(&component)
             .ok_or_else(|| anyhow::anyhow!("Component '{}' not found", component))?;
@@ -138,9 +195,8 @@ async fn update_dependencies(
         }
 
         logger::success(&format!("{} dependencies updated successfully!", component));
+        Ok(())
     }
-
-    Ok(())
 }
 
 async fn show_outdated_packages(
@@ -151,26 +207,34 @@ async fn show_outdated_packages(
     if component == "all" {
         logger::info("Checking outdated packages for all components...");
 
-        // Check backend outdated packages
-        if components.contains_key("backend") {
-            logger::info("Backend (uv) outdated packages:");
-            // uv doesn't have a direct outdated command, but we can show the lock diff
-            if let Err(_) = executor.execute_streaming("uv", &["lock", "--dry-run"], None).await {
-                logger::info("No outdated information available for uv packages");
+        let mut names: Vec<&String> = components.keys().collect();
+        names.sort();
+
+        for name in names {
+            let comp_config = &components[name];
+            let path = std::path::Path::new(&comp_config.path);
+            logger::info(&format!("{} ({}) outdated packages:", comp_config.name, comp_config.package_manager));
+
+            match comp_config.package_manager.as_str() {
+                "uv" => {
+                    if executor.execute_streaming("uv", &["lock", "--dry-run"], Some(path)).await.is_err() {
+                        logger::info("No outdated information available for uv packages");
+                    }
+                }
+                "npm" => {
+                    executor.execute_streaming("npm", &["outdated"], Some(path)).await.ok();
+                }
+                "yarn" => {
+                    executor.execute_streaming("yarn", &["outdated"], Some(path)).await.ok();
+                }
+                "pnpm" => {
+                    executor.execute_streaming("pnpm", &["outdated"], Some(path)).await.ok();
+                }
+                _ => {
+                    logger::warning(&format!("Unknown package manager: {}", comp_config.package_manager));
+                }
             }
         }
-
-        // Check frontend outdated packages
-        if components.contains_key("frontend") {
-            logger::info("Frontend (npm) outdated packages:");
-            executor.execute_streaming("npm", &["outdated"], Some(&std::path::Path::new("ui"))).await.ok();
-        }
-
-        // Check infrastructure outdated packages
-        if components.contains_key("infrastructure") {
-            logger::info("Infrastructure (npm) outdated packages:");
-            executor.execute_streaming("npm", &["outdated"], Some(&std::path::Path::new("infrastructure/cdk"))).await.ok();
-        }
     } else {
         let comp_config = components.get(&component)
             .ok_or_else(|| anyhow::anyhow!("Component '{}' not found", component))?;
@@ -203,31 +267,10 @@ async fn show_outdated_packages(
 }
 
 async fn sync_all_dependencies(
+    jobs: Option<usize>,
     components: &HashMap<String, ComponentConfig>,
     executor: &CommandExecutor,
 ) -> Result<()> {
     logger::info("Syncing all dependencies across the project...");
-
-    // This is equivalent to install all, but with explicit sync semantics
-    // Backend: uv sync (ensures virtual environment matches lockfile)
-    if components.contains_key("backend") {
-        logger::info("Syncing backend dependencies with uv...");
-        executor.execute_streaming("uv", &["sync"], None).await?;
-    }
-
-    // Frontend: npm ci (clean install from lockfile)
-    if components.contains_key("frontend") {
-        logger::info("Syncing frontend dependencies...");
-        executor.execute_streaming("npm", &["ci"], Some(&std::path::Path::new("ui"))).await?;
-    }
-
-    // Infrastructure: npm ci (clean install from lockfile)
-    if components.contains_key("infrastructure") {
-        logger::info("Syncing infrastructure dependencies...");
-        executor.execute_streaming("npm", &["ci"], Some(&std::path::Path::new("infrastructure/cdk"))).await?;
-    }
-
-    logger::success("All dependencies synced successfully!");
-
-    Ok(())
+    run_deps_parallel("sync", jobs, components, executor, sync_command_parts).await
 }
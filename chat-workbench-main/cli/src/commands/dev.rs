@@ -1,31 +1,89 @@
 use anyhow::{Context, Result};
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
 use std::path::PathBuf;
 use std::collections::HashMap;
+use std::time::Instant;
 use crate::cli::{Cli, DevCommands};
-use crate::config::ComponentConfig;
-use crate::utils::{logger, executor::CommandExecutor};
-
-pub async fn handle_dev(dev_cmd: DevCommands, components: &HashMap<String, ComponentConfig>, cli: &Cli) -> Result<()> {
-    let executor = CommandExecutor::new(cli.dry_run, cli.verbose);
+use crate::config::{ComponentConfig, EnvConfig};
+use crate::utils::{
+    logger, backend, coverage,
+    executor::{CommandExecutor, GraphNode, GraphOutcome},
+    recipe, supervisor::{self, SupervisedProcess},
+    test_report::{self, ReporterKind, TestRecord, TestStatus},
+    watch::Watcher,
+};
+
+pub async fn handle_dev(
+    dev_cmd: DevCommands,
+    components: &HashMap<String, ComponentConfig>,
+    env_config: &EnvConfig,
+    cli: &Cli,
+) -> Result<()> {
+    let executor = CommandExecutor::with_backend(cli.dry_run, cli.verbose, cli.exec_policy(), backend::backend_for_env(env_config));
 
     match dev_cmd {
         DevCommands::Start { component, backend_port, frontend_port } => {
             start_dev_server(component, backend_port, frontend_port, components, &executor).await
         }
-        DevCommands::Build { component, release } => {
-            build_components(component, release, components, &executor).await
+        DevCommands::Build { component, release, cached, profile, features, target, workspace, offline, locked, watch, jobs, shuffle } => {
+            if cached {
+                if watch {
+                    logger::warning("--watch is not supported together with --cached; running a single cached build");
+                }
+                let cook = CookOptions { release, profile, features, target, workspace, offline, locked };
+                build_components_cached(component, components, &executor, cook).await
+            } else if watch {
+                let executor = executor.clone();
+                watch_loop(&component, components, |affected| {
+                    let executor = executor.clone();
+                    async move { run_build_for(&affected, release, components, &executor).await }
+                }).await
+            } else {
+                build_components(component, release, jobs, shuffle, components, &executor).await
+            }
         }
-        DevCommands::Test { component, coverage, test } => {
-            run_tests(component, coverage, test, components, &executor).await
+        DevCommands::Test { component, coverage, fail_under, test, watch, jobs, reporter, shuffle } => {
+            let coverage = coverage || fail_under.is_some();
+            if watch {
+                if reporter != ReporterKind::Pretty || fail_under.is_some() {
+                    logger::warning("--reporter/--fail-under are ignored together with --watch; using pretty output");
+                }
+                let executor = executor.clone();
+                watch_loop(&component, components, |affected| {
+                    let executor = executor.clone();
+                    let test = test.clone();
+                    async move { run_tests_for(&affected, coverage, test.as_deref(), components, &executor).await }
+                }).await
+            } else {
+                run_tests(component, coverage, fail_under, test, jobs, reporter, shuffle, components, &executor).await
+            }
         }
-        DevCommands::Lint { component, fix } => {
-            run_linting(component, fix, components, &executor).await
+        DevCommands::Lint { component, fix, watch, jobs, shuffle } => {
+            if watch {
+                let executor = executor.clone();
+                watch_loop(&component, components, |affected| {
+                    let executor = executor.clone();
+                    async move { run_lint_for(&affected, fix, components, &executor).await }
+                }).await
+            } else {
+                run_linting(component, fix, jobs, shuffle, components, &executor).await
+            }
         }
         DevCommands::Format { component } => {
             format_code(component, components, &executor).await
         }
-        DevCommands::Typecheck { component } => {
-            run_typecheck(component, components, &executor).await
+        DevCommands::Typecheck { component, watch } => {
+            if watch {
+                let executor = executor.clone();
+                watch_loop(&component, components, |affected| {
+                    let executor = executor.clone();
+                    async move { run_typecheck_for(&affected, components, &executor).await }
+                }).await
+            } else {
+                run_typecheck(component, components, &executor).await
+            }
         }
         DevCommands::PreCommit => {
             run_pre_commit(&executor).await
@@ -33,100 +91,221 @@ pub async fn handle_dev(dev_cmd: DevCommands, components: &HashMap<String, Compo
     }
 }
 
+/// Shared `--watch` loop for `dev test|lint|typecheck|build`, modeled on
+/// Deno's file-watcher loop: run once immediately against every selected
+/// component, then re-run only the components affected by each settled
+/// batch of filesystem changes. `run` is expected to log its own per-
+/// component failures rather than return them, so a broken test/lint/build
+/// never stops the watch loop — only Ctrl-C does.
+async fn watch_loop<F, Fut>(component: &str, components: &HashMap<String, ComponentConfig>, mut run: F) -> Result<()>
+where
+    F: FnMut(Vec<String>) -> Fut,
+    Fut: std::future::Future<Output = Result<()>>,
+{
+    let scoped: HashMap<String, ComponentConfig> = if component == "all" {
+        components.clone()
+    } else {
+        let comp_config = components
+            .get(component)
+            .ok_or_else(|| anyhow::anyhow!("Component '{}' not found", component))?
+            .clone();
+        HashMap::from([(component.to_string(), comp_config)])
+    };
+    let all_names: Vec<String> = scoped.keys().cloned().collect();
+
+    run(all_names).await?;
+
+    let mut watcher = Watcher::new(&scoped)?;
+    logger::info(&format!("Watching {} path(s) for changes. Press Ctrl-C to stop.", watcher.watched_count()));
+
+    while let Some(affected) = watcher.next_batch().await {
+        if affected.is_empty() {
+            continue;
+        }
+        print!("\x1B[2J\x1B[1;1H");
+        logger::info(&format!("Watching {} path(s)... re-running: {}", watcher.watched_count(), affected.join(", ")));
+        run(affected).await?;
+    }
+
+    Ok(())
+}
+
+/// Run `command_for(comp_config)` across every component it returns a
+/// command for, as a dependency-ordered DAG keyed by
+/// `ComponentConfig.depends_on` (an edge to a component with no command for
+/// this operation is dropped, since it can't block anything here), bounded
+/// by `jobs` components running at once (defaults to the number of CPUs).
+/// A cycle among `depends_on` edges is reported as a hard error.
+///
+/// Components are dispatched in name-sorted order by default; `shuffle_seed`
+/// (resolved by `resolve_shuffle_seed` from `--shuffle [SEED]`) reorders them
+/// with a seeded RNG instead, to flush out hidden reliance on a particular
+/// ordering (e.g. components whose tests leak state into the next one run).
+async fn run_component_graph(
+    components: &HashMap<String, ComponentConfig>,
+    jobs: Option<usize>,
+    shuffle_seed: Option<u64>,
+    executor: &CommandExecutor,
+    mut command_for: impl FnMut(&ComponentConfig) -> Option<(String, Vec<String>)>,
+) -> Result<GraphOutcome> {
+    let mut commands: HashMap<String, (String, Vec<String>)> = HashMap::new();
+    for (name, comp_config) in components {
+        if let Some(cmd) = command_for(comp_config) {
+            commands.insert(name.clone(), cmd);
+        }
+    }
+
+    let mut names: Vec<String> = commands.keys().cloned().collect();
+    names.sort();
+    if let Some(seed) = shuffle_seed {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        names.shuffle(&mut rng);
+    }
+
+    let nodes: Vec<GraphNode> = names
+        .into_iter()
+        .map(|name| {
+            let (cmd, args) = &commands[&name];
+            let comp_config = &components[&name];
+            let comp_path = PathBuf::from(&comp_config.path);
+            let depends_on: Vec<String> = comp_config
+                .depends_on
+                .iter()
+                .filter(|dep| commands.contains_key(*dep))
+                .cloned()
+                .collect();
+            GraphNode::new(name.clone(), cmd.clone(), args.clone(), Some(comp_path)).depends_on(depends_on)
+        })
+        .collect();
+
+    executor.execute_dag(nodes, jobs, true).await
+}
+
+/// Resolve `--shuffle [SEED]` into the seed to actually use: `None` when the
+/// flag wasn't passed, a generated seed (logged so the run can be
+/// reproduced) for bare `--shuffle`, or the explicit seed for `--shuffle N`.
+fn resolve_shuffle_seed(shuffle: Option<Option<u64>>) -> Option<u64> {
+    let seed = match shuffle? {
+        Some(seed) => seed,
+        None => rand::random(),
+    };
+    logger::info(&format!("Shuffling component order with seed {} (pass --shuffle {} to reproduce)", seed, seed));
+    Some(seed)
+}
+
+/// Turn a `GraphOutcome` from `run_component_graph` into the logger
+/// messages `build_components`/`run_tests`/`run_linting` already print for
+/// their single-component case, and a hard error on the first failure.
+fn report_graph_outcome(outcome: GraphOutcome, action: &str, done: &str) -> Result<()> {
+    if !outcome.skipped.is_empty() {
+        logger::warning(&format!("Skipped (blocked by a failed dependency): {}", outcome.skipped.join(", ")));
+    }
+    if let Some((id, err)) = outcome.failed {
+        anyhow::bail!("Failed to {} component '{}': {}", action, id, err);
+    }
+
+    logger::success(&format!("All components {} successfully!", done));
+    Ok(())
+}
+
 async fn start_dev_server(
     component: String,
-    _backend_port: Option<u16>,
-    _frontend_port: Option<u16>,
+    backend_port: Option<u16>,
+    frontend_port: Option<u16>,
     components: &HashMap<String, ComponentConfig>,
     executor: &CommandExecutor,
 ) -> Result<()> {
+    let mut processes = Vec::new();
+
     if component == "all" {
         logger::info("Starting all development servers...");
 
-        // Start backend first
         if let Some(backend) = components.get("backend") {
-            if let Some(dev_cmd) = &backend.dev_command {
-                let parts: Vec<&str> = dev_cmd.split_whitespace().collect();
-                if !parts.is_empty() {
-                    logger::info("Starting backend development server...");
-                    let backend_path = PathBuf::from(&backend.path);
-                    // For development servers, we'd typically want to start them in background
-                    // For now, this will run sequentially
-                    executor.execute_streaming(parts[0], &parts[1..], Some(&backend_path)).await?;
-                }
+            if let Some(process) = dev_server_process(backend, backend_port.map(|p| ("PORT".to_string(), p.to_string()))) {
+                processes.push(process);
             }
         }
 
-        // Start frontend
         if let Some(frontend) = components.get("frontend") {
-            if let Some(dev_cmd) = &frontend.dev_command {
-                let parts: Vec<&str> = dev_cmd.split_whitespace().collect();
-                if !parts.is_empty() {
-                    logger::info("Starting frontend development server...");
-                    let frontend_path = PathBuf::from(&frontend.path);
-                    executor.execute_streaming(parts[0], &parts[1..], Some(&frontend_path)).await?;
-                }
+            if let Some(process) = dev_server_process(frontend, frontend_port.map(|p| ("PORT".to_string(), p.to_string()))) {
+                processes.push(process);
             }
         }
+
+        if processes.is_empty() {
+            logger::warning("No dev command configured for 'backend' or 'frontend'");
+            return Ok(());
+        }
     } else {
         let comp_config = components.get(&component)
             .ok_or_else(|| anyhow::anyhow!("Component '{}' not found", component))?;
 
-        if let Some(dev_cmd) = &comp_config.dev_command {
-            logger::info(&format!("Starting {} development server...", component));
-            let parts: Vec<&str> = dev_cmd.split_whitespace().collect();
-            if !parts.is_empty() {
-                let comp_path = PathBuf::from(&comp_config.path);
-                executor.execute_streaming(parts[0], &parts[1..], Some(&comp_path)).await?;
+        let port_env = match component.as_str() {
+            "backend" => backend_port.map(|p| ("PORT".to_string(), p.to_string())),
+            "frontend" => frontend_port.map(|p| ("PORT".to_string(), p.to_string())),
+            _ => None,
+        };
+
+        match dev_server_process(comp_config, port_env) {
+            Some(process) => processes.push(process),
+            None => {
+                logger::warning(&format!("No dev command configured for component '{}'", component));
+                return Ok(());
             }
-        } else {
-            logger::warning(&format!("No dev command configured for component '{}'", component));
         }
     }
 
-    Ok(())
+    if executor.is_dry_run() {
+        for process in &processes {
+            println!("Would start [{}]: {} {}", process.label, process.cmd, process.args.join(" "));
+        }
+        return Ok(());
+    }
+
+    logger::info("Press Ctrl-C to stop all development servers.");
+    supervisor::supervise(processes).await
+}
+
+/// Build the `SupervisedProcess` for a component's `dev_command`, injecting
+/// `port_env` (the resolved `--backend-port`/`--frontend-port` value) into
+/// the child's environment when present.
+fn dev_server_process(comp_config: &ComponentConfig, port_env: Option<(String, String)>) -> Option<SupervisedProcess> {
+    let dev_cmd = comp_config.dev_command.as_ref()?;
+    let parts: Vec<&str> = dev_cmd.split_whitespace().collect();
+    if parts.is_empty() {
+        return None;
+    }
+
+    Some(SupervisedProcess {
+        label: comp_config.name.clone(),
+        cmd: parts[0].to_string(),
+        args: parts[1..].iter().map(|s| s.to_string()).collect(),
+        working_dir: Some(PathBuf::from(&comp_config.path)),
+        envs: port_env.into_iter().collect(),
+    })
 }
 
 async fn build_components(
     component: String,
-    _release: bool,
+    release: bool,
+    jobs: Option<usize>,
+    shuffle: Option<Option<u64>>,
     components: &HashMap<String, ComponentConfig>,
     executor: &CommandExecutor,
 ) -> Result<()> {
     if component == "all" {
         logger::info("Building all components...");
 
-        let mut build_commands = Vec::new();
-
-        for (name, comp_config) in components {
-            if let Some(build_cmd) = &comp_config.build_command {
-                let parts: Vec<&str> = build_cmd.split_whitespace().collect();
-                if !parts.is_empty() {
-                    let comp_path = PathBuf::from(&comp_config.path);
-                    build_commands.push((parts[0], parts[1..].to_vec(), Some(comp_path), name.clone()));
-                }
-            }
-        }
-
-        for (cmd, args, dir, comp_name) in build_commands {
-            logger::info(&format!("Building {}...", comp_name));
-            let args_refs: Vec<&str> = args.iter().map(|s| s.as_ref()).collect();
-            executor.execute_streaming(cmd, &args_refs, dir.as_deref()).await
-                .with_context(|| format!("Failed to build component: {}", comp_name))?;
-        }
-
-        logger::success("All components built successfully!");
+        let shuffle_seed = resolve_shuffle_seed(shuffle);
+        let outcome = run_component_graph(components, jobs, shuffle_seed, executor, build_command_parts).await?;
+        report_graph_outcome(outcome, "build", "built")?;
     } else {
         let comp_config = components.get(&component)
             .ok_or_else(|| anyhow::anyhow!("Component '{}' not found", component))?;
 
-        if let Some(build_cmd) = &comp_config.build_command {
+        if comp_config.build_command.is_some() {
             logger::info(&format!("Building {}...", component));
-            let parts: Vec<&str> = build_cmd.split_whitespace().collect();
-            if !parts.is_empty() {
-                let comp_path = PathBuf::from(&comp_config.path);
-                executor.execute_streaming(parts[0], &parts[1..], Some(&comp_path)).await?;
-            }
+            run_component_build(comp_config, release, executor).await?;
             logger::success(&format!("{} built successfully!", component));
         } else {
             logger::warning(&format!("No build command configured for component '{}'", component));
@@ -136,64 +315,270 @@ async fn build_components(
     Ok(())
 }
 
+/// Split a component's configured build command into `(cmd, args)`, or
+/// `None` if it has none (or it's blank). Shared by the single-component
+/// and dependency-graph build paths so they agree on what "the build
+/// command" for a component is.
+fn build_command_parts(comp_config: &ComponentConfig) -> Option<(String, Vec<String>)> {
+    let build_cmd = comp_config.build_command.as_ref()?;
+    let mut parts: Vec<String> = build_cmd.split_whitespace().map(|s| s.to_string()).collect();
+    if parts.is_empty() {
+        return None;
+    }
+    let cmd = parts.remove(0);
+    Some((cmd, parts))
+}
+
+async fn run_component_build(comp_config: &ComponentConfig, _release: bool, executor: &CommandExecutor) -> Result<()> {
+    let Some((cmd, args)) = build_command_parts(comp_config) else {
+        return Ok(());
+    };
+    let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    let comp_path = PathBuf::from(&comp_config.path);
+    executor.execute_streaming(&cmd, &args_refs, Some(&comp_path)).await?;
+
+    Ok(())
+}
+
+/// `--watch` re-run for `dev build`: re-build only the components affected
+/// by the last settled batch of filesystem changes, logging (not
+/// propagating) any one component's failure so the watch loop keeps going.
+async fn run_build_for(
+    affected: &[String],
+    release: bool,
+    components: &HashMap<String, ComponentConfig>,
+    executor: &CommandExecutor,
+) -> Result<()> {
+    for name in affected {
+        let Some(comp_config) = components.get(name) else { continue };
+        if comp_config.build_command.is_none() {
+            continue;
+        }
+        logger::info(&format!("Building {}...", name));
+        if let Err(err) = run_component_build(comp_config, release, executor).await {
+            logger::error(&format!("Build failed for component '{}': {:#}", name, err));
+        }
+    }
+
+    Ok(())
+}
+
+/// Cook flags threaded through from `cwb dev build --cached`, carried as one
+/// value so `build_components_cached` doesn't need a seven-argument signature.
+struct CookOptions {
+    release: bool,
+    profile: Option<String>,
+    features: Vec<String>,
+    target: Option<String>,
+    workspace: bool,
+    offline: bool,
+    locked: bool,
+}
+
+/// `cwb dev build --cached`: prepare a cargo-chef-style recipe from each
+/// component's manifests, write it alongside a generated Dockerfile, then
+/// cook (install) only the dependency layer so it can be cached independently
+/// of application source changes.
+async fn build_components_cached(
+    component: String,
+    components: &HashMap<String, ComponentConfig>,
+    executor: &CommandExecutor,
+    cook: CookOptions,
+) -> Result<()> {
+    let profile = cook.profile.unwrap_or_else(|| if cook.release { "release" } else { "debug" }.to_string());
+
+    let targets: Vec<&ComponentConfig> = if component == "all" {
+        components.values().collect()
+    } else {
+        vec![components
+            .get(&component)
+            .ok_or_else(|| anyhow::anyhow!("Component '{}' not found", component))?]
+    };
+
+    for comp_config in targets {
+        logger::info(&format!("Preparing dependency recipe for {}...", comp_config.name));
+
+        let built = recipe::prepare_recipe(
+            comp_config,
+            profile.clone(),
+            cook.features.clone(),
+            cook.target.clone(),
+            cook.workspace,
+            cook.offline,
+            cook.locked,
+        )?;
+
+        if built.manifest_files.is_empty() {
+            logger::warning(&format!(
+                "No {} manifests found for component '{}', skipping cached build",
+                comp_config.package_manager, comp_config.name
+            ));
+            continue;
+        }
+
+        let cache_dir = recipe::cache_dir(comp_config);
+        let recipe_path = cache_dir.join("recipe.json");
+        let dockerfile_path = cache_dir.join("Dockerfile");
+
+        recipe::write_recipe(&built, &recipe_path)
+            .with_context(|| format!("Failed to write recipe for component: {}", comp_config.name))?;
+        std::fs::write(&dockerfile_path, recipe::generate_dockerfile(&built))
+            .with_context(|| format!("Failed to write Dockerfile for component: {}", comp_config.name))?;
+
+        logger::info(&format!(
+            "Recipe fingerprint {} written to {}",
+            &built.fingerprint()[..12],
+            recipe_path.display()
+        ));
+
+        let (cmd, args) = recipe::cook_command(&built);
+        let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        let comp_path = PathBuf::from(&comp_config.path);
+
+        logger::info(&format!("Cooking dependency layer for {}...", comp_config.name));
+        executor
+            .execute_streaming(&cmd, &args_refs, Some(&comp_path))
+            .await
+            .with_context(|| format!("Failed to cook dependencies for component: {}", comp_config.name))?;
+    }
+
+    logger::success("Dependency layer(s) cached successfully!");
+    Ok(())
+}
+
 async fn run_tests(
     component: String,
     coverage: bool,
+    fail_under: Option<f64>,
     test_filter: Option<String>,
+    jobs: Option<usize>,
+    reporter_kind: ReporterKind,
+    shuffle: Option<Option<u64>>,
     components: &HashMap<String, ComponentConfig>,
     executor: &CommandExecutor,
 ) -> Result<()> {
+    let reporter = test_report::reporter_for(reporter_kind);
+    let coverage_dest = PathBuf::from("coverage").join("lcov.info");
+
     if component == "all" {
         logger::info("Running tests for all components...");
 
-        for (name, comp_config) in components {
-            if let Some(test_cmd) = &comp_config.test_command {
-                logger::info(&format!("Testing {}...", name));
-                run_component_test(comp_config, test_cmd, coverage, test_filter.as_deref(), executor).await
-                    .with_context(|| format!("Tests failed for component: {}", name))?;
+        let shuffle_seed = resolve_shuffle_seed(shuffle);
+        let mut test_commands: HashMap<String, (String, Vec<String>)> = HashMap::new();
+        let outcome = run_component_graph(components, jobs, shuffle_seed, executor, |comp_config| {
+            let parts = test_command_parts(comp_config, coverage, test_filter.as_deref());
+            if let Some((cmd, args)) = &parts {
+                test_commands.insert(comp_config.name.clone(), (cmd.clone(), args.clone()));
             }
+            parts
+        }).await?;
+
+        let records = test_records_from_outcome(&outcome, &test_commands, test_filter.as_deref());
+        reporter.report(&records)?;
+
+        if coverage {
+            let reports: Vec<(ComponentConfig, PathBuf)> = test_commands
+                .keys()
+                .filter_map(|name| components.get(name))
+                .filter_map(|comp_config| coverage::report_path(comp_config).map(|p| (comp_config.clone(), p)))
+                .collect();
+            let total = coverage::merge_reports(&reports, &coverage_dest)?;
+            check_fail_under(total.line_pct(), fail_under)?;
         }
 
-        logger::success("All tests passed!");
+        report_graph_outcome(outcome, "test", "passed")?;
     } else {
         let comp_config = components.get(&component)
             .ok_or_else(|| anyhow::anyhow!("Component '{}' not found", component))?;
 
-        if let Some(test_cmd) = &comp_config.test_command {
-            logger::info(&format!("Running tests for {}...", component));
-            run_component_test(comp_config, test_cmd, coverage, test_filter.as_deref(), executor).await?;
-            logger::success(&format!("{} tests passed!", component));
-        } else {
+        let Some((cmd, args)) = test_command_parts(comp_config, coverage, test_filter.as_deref()) else {
             logger::warning(&format!("No test command configured for component '{}'", component));
+            return Ok(());
+        };
+
+        logger::info(&format!("Running tests for {}...", component));
+        let started = Instant::now();
+        let result = run_component_test(comp_config, coverage, test_filter.as_deref(), executor).await;
+        let full_command = format!("{} {}", cmd, args.join(" "));
+        let status = if result.is_ok() { TestStatus::Passed } else { TestStatus::Failed };
+        let record = TestRecord::new(&component, status, started.elapsed(), full_command, test_filter.as_deref());
+        reporter.report(&[record])?;
+
+        if coverage {
+            if let Some(report_path) = coverage::report_path(comp_config) {
+                let total = coverage::merge_reports(&[(comp_config.clone(), report_path)], &coverage_dest)?;
+                check_fail_under(total.line_pct(), fail_under)?;
+            } else {
+                logger::warning(&format!("Coverage not supported for component '{}' ({})", component, comp_config.package_manager));
+            }
         }
+
+        result?;
+        logger::success(&format!("{} tests passed!", component));
     }
 
     Ok(())
 }
 
-async fn run_component_test(
-    comp_config: &ComponentConfig,
-    base_test_cmd: &str,
-    coverage: bool,
+/// Enforce `--fail-under P` against a computed total line coverage
+/// percentage, as a hard error so CI can gate on it.
+fn check_fail_under(line_pct: f64, fail_under: Option<f64>) -> Result<()> {
+    if let Some(threshold) = fail_under {
+        if line_pct < threshold {
+            anyhow::bail!("Total line coverage {:.1}% is below the required {:.1}%", line_pct, threshold);
+        }
+    }
+    Ok(())
+}
+
+/// Turn a test-run `GraphOutcome` into `TestRecord`s: one per component that
+/// had a test command, deriving `Passed`/`Failed`/`Skipped` from which bucket
+/// `execute_dag` sorted it into.
+fn test_records_from_outcome(
+    outcome: &GraphOutcome,
+    test_commands: &HashMap<String, (String, Vec<String>)>,
     test_filter: Option<&str>,
-    executor: &CommandExecutor,
-) -> Result<()> {
+) -> Vec<TestRecord> {
+    let mut names: Vec<&String> = test_commands.keys().collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let (cmd, args) = &test_commands[name];
+            let full_command = format!("{} {}", cmd, args.join(" "));
+            let status = if outcome.failed.as_ref().is_some_and(|(id, _)| id == name) {
+                TestStatus::Failed
+            } else if outcome.skipped.contains(name) {
+                TestStatus::Skipped
+            } else {
+                TestStatus::Passed
+            };
+            let duration = outcome.durations.get(name).copied().unwrap_or_default();
+            TestRecord::new(name.clone(), status, duration, full_command, test_filter)
+        })
+        .collect()
+}
+
+/// Build a component's test command, folding in coverage/test-filter flags
+/// for its package manager, or `None` if it has no test command. Shared by
+/// the single-component and dependency-graph test paths so they agree on
+/// what "the test command" for a component is.
+fn test_command_parts(comp_config: &ComponentConfig, coverage: bool, test_filter: Option<&str>) -> Option<(String, Vec<String>)> {
+    let base_test_cmd = comp_config.test_command.as_ref()?;
     let mut cmd_parts: Vec<String> = base_test_cmd.split_whitespace().map(|s| s.to_string()).collect();
+    if cmd_parts.is_empty() {
+        return None;
+    }
 
-    // Add coverage flags based on package manager and language
+    // Instrument for coverage: cargo's test command is replaced wholesale
+    // (cargo-llvm-cov runs the tests itself), everyone else just gets
+    // extra flags appended.
     if coverage {
-        match comp_config.package_manager.as_str() {
-            "npm" | "yarn" | "pnpm" => {
-                // For Node.js projects, coverage is usually handled by the test runner
-                // This would depend on your specific setup
-            }
-            "uv" | "pip" => {
-                // For Python projects with pytest
-                if base_test_cmd.contains("pytest") {
-                    cmd_parts.push("--cov".to_string());
-                }
-            }
-            _ => {}
+        if let Some(rewritten) = coverage::cargo_llvm_cov_parts(&cmd_parts) {
+            cmd_parts = rewritten;
+        } else if let Some(extra) = coverage::coverage_args(comp_config, &cmd_parts) {
+            cmd_parts.extend(extra);
         }
     }
 
@@ -214,11 +599,46 @@ async fn run_component_test(
         }
     }
 
+    let cmd = cmd_parts.remove(0);
+    Some((cmd, cmd_parts))
+}
+
+async fn run_component_test(
+    comp_config: &ComponentConfig,
+    coverage: bool,
+    test_filter: Option<&str>,
+    executor: &CommandExecutor,
+) -> Result<()> {
+    let Some((cmd, args)) = test_command_parts(comp_config, coverage, test_filter) else {
+        return Ok(());
+    };
+    let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
     let comp_path = PathBuf::from(&comp_config.path);
-    let cmd = &cmd_parts[0];
-    let args: Vec<&str> = cmd_parts[1..].iter().map(|s| s.as_str()).collect();
+    executor.execute_streaming(&cmd, &args_refs, Some(&comp_path)).await?;
 
-    executor.execute_streaming(cmd, &args, Some(&comp_path)).await?;
+    Ok(())
+}
+
+/// `--watch` re-run for `dev test`: re-test only the components affected by
+/// the last settled batch of filesystem changes, logging (not propagating)
+/// any one component's failure so the watch loop keeps going.
+async fn run_tests_for(
+    affected: &[String],
+    coverage: bool,
+    test_filter: Option<&str>,
+    components: &HashMap<String, ComponentConfig>,
+    executor: &CommandExecutor,
+) -> Result<()> {
+    for name in affected {
+        let Some(comp_config) = components.get(name) else { continue };
+        if comp_config.test_command.is_none() {
+            continue;
+        }
+        logger::info(&format!("Testing {}...", name));
+        if let Err(err) = run_component_test(comp_config, coverage, test_filter, executor).await {
+            logger::error(&format!("Tests failed for component '{}': {:#}", name, err));
+        }
+    }
 
     Ok(())
 }
@@ -226,28 +646,24 @@ async fn run_component_test(
 async fn run_linting(
     component: String,
     fix: bool,
+    jobs: Option<usize>,
+    shuffle: Option<Option<u64>>,
     components: &HashMap<String, ComponentConfig>,
     executor: &CommandExecutor,
 ) -> Result<()> {
     if component == "all" {
         logger::info("Running linting for all components...");
 
-        for (name, comp_config) in components {
-            if let Some(lint_cmd) = &comp_config.lint_command {
-                logger::info(&format!("Linting {}...", name));
-                run_component_lint(comp_config, lint_cmd, fix, executor).await
-                    .with_context(|| format!("Linting failed for component: {}", name))?;
-            }
-        }
-
-        logger::success("All linting checks passed!");
+        let shuffle_seed = resolve_shuffle_seed(shuffle);
+        let outcome = run_component_graph(components, jobs, shuffle_seed, executor, |comp_config| lint_command_parts(comp_config, fix)).await?;
+        report_graph_outcome(outcome, "lint", "passed linting")?;
     } else {
         let comp_config = components.get(&component)
             .ok_or_else(|| anyhow::anyhow!("Component '{}' not found", component))?;
 
-        if let Some(lint_cmd) = &comp_config.lint_command {
+        if comp_config.lint_command.is_some() {
             logger::info(&format!("Linting {}...", component));
-            run_component_lint(comp_config, lint_cmd, fix, executor).await?;
+            run_component_lint(comp_config, fix, executor).await?;
             logger::success(&format!("{} linting passed!", component));
         } else {
             logger::warning(&format!("No lint command configured for component '{}'", component));
@@ -257,13 +673,16 @@ async fn run_linting(
     Ok(())
 }
 
-async fn run_component_lint(
-    comp_config: &ComponentConfig,
-    base_lint_cmd: &str,
-    fix: bool,
-    executor: &CommandExecutor,
-) -> Result<()> {
+/// Build a component's lint command, folding in `--fix` for the linters
+/// that support it, or `None` if it has no lint command. Shared by the
+/// single-component and dependency-graph lint paths so they agree on what
+/// "the lint command" for a component is.
+fn lint_command_parts(comp_config: &ComponentConfig, fix: bool) -> Option<(String, Vec<String>)> {
+    let base_lint_cmd = comp_config.lint_command.as_ref()?;
     let mut cmd_parts: Vec<String> = base_lint_cmd.split_whitespace().map(|s| s.to_string()).collect();
+    if cmd_parts.is_empty() {
+        return None;
+    }
 
     // Add fix flags based on the linter
     if fix {
@@ -274,11 +693,40 @@ async fn run_component_lint(
         }
     }
 
+    let cmd = cmd_parts.remove(0);
+    Some((cmd, cmd_parts))
+}
+
+async fn run_component_lint(comp_config: &ComponentConfig, fix: bool, executor: &CommandExecutor) -> Result<()> {
+    let Some((cmd, args)) = lint_command_parts(comp_config, fix) else {
+        return Ok(());
+    };
+    let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
     let comp_path = PathBuf::from(&comp_config.path);
-    let cmd = &cmd_parts[0];
-    let args: Vec<&str> = cmd_parts[1..].iter().map(|s| s.as_str()).collect();
+    executor.execute_streaming(&cmd, &args_refs, Some(&comp_path)).await?;
 
-    executor.execute_streaming(cmd, &args, Some(&comp_path)).await?;
+    Ok(())
+}
+
+/// `--watch` re-run for `dev lint`: re-lint only the components affected by
+/// the last settled batch of filesystem changes, logging (not propagating)
+/// any one component's failure so the watch loop keeps going.
+async fn run_lint_for(
+    affected: &[String],
+    fix: bool,
+    components: &HashMap<String, ComponentConfig>,
+    executor: &CommandExecutor,
+) -> Result<()> {
+    for name in affected {
+        let Some(comp_config) = components.get(name) else { continue };
+        if comp_config.lint_command.is_none() {
+            continue;
+        }
+        logger::info(&format!("Linting {}...", name));
+        if let Err(err) = run_component_lint(comp_config, fix, executor).await {
+            logger::error(&format!("Linting failed for component '{}': {:#}", name, err));
+        }
+    }
 
     Ok(())
 }
@@ -333,11 +781,9 @@ async fn run_typecheck(
         logger::info("Running type checking for all components...");
 
         for (name, comp_config) in components {
-            // For TypeScript components, we can run tsc --noEmit
             if comp_config.language == "typescript" {
                 logger::info(&format!("Type checking {}...", name));
-                let comp_path = PathBuf::from(&comp_config.path);
-                executor.execute_streaming("npx", &["tsc", "--noEmit"], Some(&comp_path)).await
+                run_component_typecheck(comp_config, executor).await
                     .with_context(|| format!("Type checking failed for component: {}", name))?;
             }
         }
@@ -349,8 +795,7 @@ async fn run_typecheck(
 
         if comp_config.language == "typescript" {
             logger::info(&format!("Type checking {}...", component));
-            let comp_path = PathBuf::from(&comp_config.path);
-            executor.execute_streaming("npx", &["tsc", "--noEmit"], Some(&comp_path)).await?;
+            run_component_typecheck(comp_config, executor).await?;
             logger::success(&format!("{} type checking passed!", component));
         } else {
             logger::warning(&format!("Type checking not supported for {} ({} language)", component, comp_config.language));
@@ -360,6 +805,36 @@ async fn run_typecheck(
     Ok(())
 }
 
+/// For TypeScript components, we can run `tsc --noEmit`.
+async fn run_component_typecheck(comp_config: &ComponentConfig, executor: &CommandExecutor) -> Result<()> {
+    let comp_path = PathBuf::from(&comp_config.path);
+    executor.execute_streaming("npx", &["tsc", "--noEmit"], Some(&comp_path)).await?;
+
+    Ok(())
+}
+
+/// `--watch` re-run for `dev typecheck`: re-check only the components
+/// affected by the last settled batch of filesystem changes, logging (not
+/// propagating) any one component's failure so the watch loop keeps going.
+async fn run_typecheck_for(
+    affected: &[String],
+    components: &HashMap<String, ComponentConfig>,
+    executor: &CommandExecutor,
+) -> Result<()> {
+    for name in affected {
+        let Some(comp_config) = components.get(name) else { continue };
+        if comp_config.language != "typescript" {
+            continue;
+        }
+        logger::info(&format!("Type checking {}...", name));
+        if let Err(err) = run_component_typecheck(comp_config, executor).await {
+            logger::error(&format!("Type checking failed for component '{}': {:#}", name, err));
+        }
+    }
+
+    Ok(())
+}
+
 async fn run_pre_commit(executor: &CommandExecutor) -> Result<()> {
     logger::info("Running pre-commit hooks...");
 
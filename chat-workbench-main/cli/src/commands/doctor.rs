@@ -1,16 +1,19 @@
 use anyhow::Result;
 use std::path::Path;
+use std::time::Instant;
 use crate::cli::Cli;
 use crate::config::{ProjectConfig, EnvConfig};
-use crate::utils::{logger, executor::CommandExecutor};
+use crate::utils::{logger, backend, executor::CommandExecutor, notifier::{Notifier, NotificationEvent}};
 use colored::Colorize;
 
 pub async fn handle_doctor(project_config: &ProjectConfig, env_config: &EnvConfig, cli: &Cli) -> Result<()> {
     logger::info("Running cwb doctor...");
     println!();
 
+    let started = Instant::now();
+    let notifier = Notifier::new(env_config.notifications.clone(), cli.dry_run);
     let mut issues = Vec::new();
-    let executor = CommandExecutor::new(cli.dry_run, cli.verbose);
+    let executor = CommandExecutor::with_backend(cli.dry_run, cli.verbose, cli.exec_policy(), backend::backend_for_env(env_config));
 
     // Check 1: Configuration file
     check_config_file(&mut issues, project_config).await;
@@ -27,10 +30,17 @@ pub async fn handle_doctor(project_config: &ProjectConfig, env_config: &EnvConfi
     // Check 5: Git repository
     check_git_repository(&mut issues).await;
 
+    // Check 6: Remote execution endpoint (if this environment is configured
+    // to build/deploy against a remote host instead of locally)
+    check_remote_backend(&mut issues, &executor, env_config).await;
+
     // Summary
     println!();
     if issues.is_empty() {
         logger::success("All checks passed! Your setup looks good.");
+        notifier
+            .notify(NotificationEvent::succeeded("doctor", &env_config.deployment_name, started.elapsed()))
+            .await;
     } else {
         logger::warning(&format!("Found {} issue(s):", issues.len()));
         for (i, issue) in issues.iter().enumerate() {
@@ -38,6 +48,14 @@ pub async fn handle_doctor(project_config: &ProjectConfig, env_config: &EnvConfi
         }
         println!();
         println!("Please address these issues for the best cwb experience.");
+        notifier
+            .notify(NotificationEvent::failed(
+                "doctor",
+                &env_config.deployment_name,
+                started.elapsed(),
+                issues.join("; "),
+            ))
+            .await;
     }
 
     Ok(())
@@ -160,3 +178,35 @@ async fn check_git_repository(_issues: &mut Vec<String>) {
         // issues.push("Not a Git repository. Consider initializing with 'git init'.".to_string());
     }
 }
+
+async fn check_remote_backend(issues: &mut Vec<String>, executor: &CommandExecutor, env_config: &EnvConfig) {
+    let Some(remote) = &env_config.remote else {
+        return;
+    };
+
+    print!("Checking remote execution endpoint... ");
+
+    let Some(required_version) = remote.required_api_version() else {
+        println!("{}", "✓".green());
+        return;
+    };
+
+    match executor.execute("docker", &["version", "--format", "{{.Server.APIVersion}}"], None).await {
+        Ok(output) => {
+            let actual_version = output.trim();
+            if actual_version == required_version {
+                println!("{}", "✓".green());
+            } else {
+                println!("{}", "✗".red());
+                issues.push(format!(
+                    "Remote Docker API version mismatch: endpoint reports '{}' but config requires '{}'",
+                    actual_version, required_version
+                ));
+            }
+        }
+        Err(_) => {
+            println!("{}", "✗".red());
+            issues.push("Could not reach the configured remote Docker daemon to verify its API version".to_string());
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use anyhow::{Context, Result};
 use crate::cli::{Cli, EnvCommands};
-use crate::config::{CwbConfig, EnvironmentConfig, find_config_file};
-use crate::utils::{logger, prompts};
+use crate::config::{CwbConfig, EnvironmentConfig, KubernetesConfig, find_cwb_config_file};
+use crate::utils::{aws_config, env_profile, gcp_config, kubeconfig, logger, prompts};
 use colored::Colorize;
 
 pub async fn handle_env(env_cmd: EnvCommands, cli: &Cli) -> Result<()> {
@@ -15,7 +15,7 @@ pub async fn handle_env(env_cmd: EnvCommands, cli: &Cli) -> Result<()> {
 }
 
 async fn list_environments(_cli: &Cli) -> Result<()> {
-    let config_path = find_config_file()?;
+    let config_path = find_cwb_config_file()?;
 
     if !config_path.exists() {
         logger::error("No configuration file found. Run 'cwb init' to create one.");
@@ -30,13 +30,16 @@ async fn list_environments(_cli: &Cli) -> Result<()> {
     let current_env = config.current_environment.as_deref();
 
     for (name, env_config) in &config.environments {
-        let marker = if Some(name.as_str()) == current_env {
-            "●".green()
-        } else {
-            "○".white()
-        };
+        let policy = env_profile::resolve(name, &config.environment_profiles);
+        let is_current = Some(name.as_str()) == current_env;
+
+        let symbol = policy.icon.as_deref().unwrap_or(if is_current { "●" } else { "○" }).to_string();
+        let default_color = if is_current { "green" } else { "white" };
+        let marker = symbol.color(policy.color.as_deref().unwrap_or(default_color));
 
-        println!("  {} {} ({})", marker, name.bold(), env_config.aws_region);
+        let label = if policy.protected { format!("{} {}", name.bold(), "[protected]".red()) } else { name.bold().to_string() };
+
+        println!("  {} {} ({})", marker, label, env_config.aws_region);
 
         if let Some(profile) = &env_config.aws_profile {
             println!("    AWS Profile: {}", profile);
@@ -46,6 +49,14 @@ async fn list_environments(_cli: &Cli) -> Result<()> {
             println!("    AWS Account: {}", account);
         }
 
+        if let Some(project) = &env_config.gcp_project {
+            println!("    GCP Project: {}", project);
+        }
+
+        if let Some(region) = &env_config.gcp_region {
+            println!("    GCP Region: {}", region);
+        }
+
         println!();
     }
 
@@ -59,7 +70,7 @@ async fn list_environments(_cli: &Cli) -> Result<()> {
 }
 
 async fn create_environment(name: String, from: Option<String>, cli: &Cli) -> Result<()> {
-    let config_path = find_config_file()?;
+    let config_path = find_cwb_config_file()?;
 
     if !config_path.exists() {
         logger::error("No configuration file found. Run 'cwb init' to create one.");
@@ -90,16 +101,73 @@ async fn create_environment(name: String, from: Option<String>, cli: &Cli) -> Re
         logger::info(&format!("Creating environment '{}' from '{}'", name, source_env));
         source_config
     } else {
-        // Create with default values
+        // Create with values resolved from the AWS shared config/credentials
+        // files for a profile matching the ambient profile (the one a shell
+        // wrapper like aws-vault/awsu/awsume has already assumed), falling
+        // back to the environment name, so the user only has to confirm
+        // rather than type everything by hand.
         logger::info(&format!("Creating environment '{}'", name));
 
-        let aws_region = prompts::input_string("AWS Region", Some("us-east-1"))?;
-        let aws_profile = prompts::input_string("AWS Profile (optional)", Some(&name))?;
+        let default_profile = aws_config::ambient_profile().unwrap_or_else(|| name.clone());
+        let resolved = aws_config::resolve_profile(&default_profile);
+        let default_region = aws_config::ambient_region().or(resolved.region);
+
+        let (aws_region, aws_profile, aws_account_id) = if cli.force {
+            (
+                default_region.unwrap_or_else(|| "us-east-1".to_string()),
+                Some(default_profile),
+                resolved.account_id,
+            )
+        } else {
+            let aws_region = prompts::input_string(
+                "AWS Region",
+                Some(default_region.as_deref().unwrap_or("us-east-1")),
+            )?;
+            let aws_profile = prompts::input_string("AWS Profile (optional)", Some(&default_profile))?;
+
+            (aws_region, if aws_profile.is_empty() { None } else { Some(aws_profile) }, resolved.account_id)
+        };
+
+        // Prefill GCP project/region from gcloud's active configuration,
+        // if one exists, so AWS+GCP projects get the same workflow.
+        let gcp = gcp_config::resolve_active_config();
+        let (gcp_project, gcp_region) = match gcp {
+            Some(active) if cli.force => (active.project, active.region),
+            Some(active) => {
+                let gcp_project = prompts::input_string(
+                    "GCP Project (optional)",
+                    active.project.as_deref(),
+                )?;
+                let gcp_region = prompts::input_string(
+                    "GCP Region (optional)",
+                    active.region.as_deref(),
+                )?;
+                (
+                    if gcp_project.is_empty() { None } else { Some(gcp_project) },
+                    if gcp_region.is_empty() { None } else { Some(gcp_region) },
+                )
+            }
+            None => (None, None),
+        };
+
+        // Bind whatever kube context is currently active, so the environment
+        // carries the cluster/namespace an operator is actually pointed at.
+        let kubernetes = kubeconfig::resolve_current_context().map(|ctx| {
+            logger::info(&format!("Detected kube context '{}'", ctx.context));
+            KubernetesConfig {
+                context: ctx.context,
+                cluster: ctx.cluster,
+                namespace: ctx.namespace,
+            }
+        });
 
         EnvironmentConfig {
             aws_region,
-            aws_profile: if aws_profile.is_empty() { None } else { Some(aws_profile) },
-            aws_account_id: None,
+            aws_profile,
+            aws_account_id,
+            gcp_project,
+            gcp_region,
+            kubernetes,
             variables: None,
         }
     };
@@ -121,7 +189,7 @@ async fn create_environment(name: String, from: Option<String>, cli: &Cli) -> Re
 }
 
 async fn switch_environment(name: String, _cli: &Cli) -> Result<()> {
-    let config_path = find_config_file()?;
+    let config_path = find_cwb_config_file()?;
 
     if !config_path.exists() {
         logger::error("No configuration file found. Run 'cwb init' to create one.");
@@ -134,9 +202,32 @@ async fn switch_environment(name: String, _cli: &Cli) -> Result<()> {
         anyhow::bail!("Environment '{}' not found", name);
     }
 
+    // Protected environments (e.g. prod matched by an `environment_profiles`
+    // entry) always require typed confirmation, even under `--force`, since
+    // `--force` is meant to skip routine prompts, not this guard rail.
+    let policy = env_profile::resolve(&name, &config.environment_profiles);
+    if policy.protected {
+        let confirmed = prompts::confirm_destructive("switch to protected environment", &name)?;
+        if !confirmed {
+            logger::info("Environment switch cancelled");
+            return Ok(());
+        }
+    }
+
     let previous = config.current_environment.clone();
     config.current_environment = Some(name.clone());
 
+    // If this environment was created without a profile, pick up the one
+    // the ambient shell has already assumed rather than leaving it unset.
+    if let Some(env_config) = config.environments.get_mut(&name) {
+        if env_config.aws_profile.is_none() {
+            if let Some(profile) = aws_config::ambient_profile() {
+                logger::info(&format!("Detected ambient AWS profile '{}' for '{}'", profile, name));
+                env_config.aws_profile = Some(profile);
+            }
+        }
+    }
+
     config.save(&config_path)
         .context("Failed to save configuration")?;
 
@@ -150,7 +241,7 @@ async fn switch_environment(name: String, _cli: &Cli) -> Result<()> {
 }
 
 async fn delete_environment(name: String, cli: &Cli) -> Result<()> {
-    let config_path = find_config_file()?;
+    let config_path = find_cwb_config_file()?;
 
     if !config_path.exists() {
         logger::error("No configuration file found. Run 'cwb init' to create one.");
@@ -168,8 +259,11 @@ async fn delete_environment(name: String, cli: &Cli) -> Result<()> {
         anyhow::bail!("Cannot delete current environment. Switch to another environment first.");
     }
 
-    // Confirmation
-    if !cli.force {
+    let policy = env_profile::resolve(&name, &config.environment_profiles);
+
+    // Confirmation; protected environments always confirm, `--force` only
+    // skips the routine prompt for everything else.
+    if policy.protected || !cli.force {
         let confirmed = prompts::confirm_destructive("delete environment", &name)?;
         if !confirmed {
             logger::info("Environment deletion cancelled");
@@ -188,7 +282,7 @@ async fn delete_environment(name: String, cli: &Cli) -> Result<()> {
 }
 
 async fn show_environment(name: Option<String>, _cli: &Cli) -> Result<()> {
-    let config_path = find_config_file()?;
+    let config_path = find_cwb_config_file()?;
 
     if !config_path.exists() {
         logger::error("No configuration file found. Run 'cwb init' to create one.");
@@ -219,6 +313,40 @@ async fn show_environment(name: Option<String>, _cli: &Cli) -> Result<()> {
         println!("AWS Account: {}", account);
     }
 
+    if let Some(project) = &env_config.gcp_project {
+        println!("GCP Project: {}", project);
+    }
+
+    if let Some(region) = &env_config.gcp_region {
+        println!("GCP Region: {}", region);
+    }
+
+    // Prefer the bound kube context, falling back to whatever is live on
+    // this machine right now if the environment was created before this
+    // field existed or the context was never bound.
+    match &env_config.kubernetes {
+        Some(k8s) => {
+            println!("Kubernetes Context: {}", k8s.context);
+            if let Some(cluster) = &k8s.cluster {
+                println!("  Cluster: {}", cluster);
+            }
+            if let Some(namespace) = &k8s.namespace {
+                println!("  Namespace: {}", namespace);
+            }
+        }
+        None => {
+            if let Some(ctx) = kubeconfig::resolve_current_context() {
+                println!("Kubernetes Context: {} (live, not bound)", ctx.context);
+                if let Some(cluster) = &ctx.cluster {
+                    println!("  Cluster: {}", cluster);
+                }
+                if let Some(namespace) = &ctx.namespace {
+                    println!("  Namespace: {}", namespace);
+                }
+            }
+        }
+    }
+
     if let Some(variables) = &env_config.variables {
         if !variables.is_empty() {
             println!("Environment Variables:");
@@ -1,7 +1,7 @@
 use anyhow::{Context, Result};
 use std::path::Path;
 use crate::cli::Cli;
-use crate::config::CwbConfig;
+use crate::config::{ComponentConfig, CwbConfig};
 use crate::utils::{logger, prompts};
 
 pub async fn handle_init(name: Option<String>, project_type: String, cli: &Cli) -> Result<()> {
@@ -65,16 +65,22 @@ fn detect_project_structure(config: &mut CwbConfig) -> Result<()> {
         ("frontend", "./frontend", "Frontend detected"),
         ("infrastructure", "./infrastructure/cdk", "CDK infrastructure detected"),
         ("infrastructure", "./infra", "Infrastructure detected"),
+        ("kubernetes", "./k8s", "Kubernetes manifests detected"),
+        ("kubernetes", "./kubernetes", "Kubernetes manifests detected"),
+        ("gcp", "./infrastructure/terraform", "GCP Terraform infrastructure detected"),
     ];
 
     for (component_type, path, message) in paths_to_check {
         if Path::new(path).exists() {
             logger::info(message);
 
-            // Update the component path if it exists in config
-            if let Some(component) = config.components.get_mut(component_type) {
-                component.path = path.to_string();
-            }
+            // `kubernetes`/`gcp` aren't part of the built-in component set
+            // (unlike backend/frontend/infrastructure, not every project has
+            // them), so detecting one for the first time must add it rather
+            // than update-if-present.
+            let component = config.components.entry(component_type.to_string())
+                .or_insert_with(|| new_detected_component(component_type, path));
+            component.path = path.to_string();
         }
     }
 
@@ -82,7 +88,7 @@ fn detect_project_structure(config: &mut CwbConfig) -> Result<()> {
     if Path::new("./backend/pyproject.toml").exists() {
         logger::info("Python project with pyproject.toml detected");
         if let Some(backend) = config.components.get_mut("backend") {
-            backend.package_manager = crate::config::PackageManager::Uv;
+            backend.package_manager = "uv".to_string();
         }
     }
 
@@ -91,18 +97,46 @@ fn detect_project_structure(config: &mut CwbConfig) -> Result<()> {
         // Check for specific package managers
         if Path::new("./ui/yarn.lock").exists() {
             if let Some(frontend) = config.components.get_mut("frontend") {
-                frontend.package_manager = crate::config::PackageManager::Yarn;
+                frontend.package_manager = "yarn".to_string();
             }
         } else if Path::new("./ui/pnpm-lock.yaml").exists() {
             if let Some(frontend) = config.components.get_mut("frontend") {
-                frontend.package_manager = crate::config::PackageManager::Pnpm;
+                frontend.package_manager = "pnpm".to_string();
             }
         } else if Path::new("./ui/bun.lockb").exists() {
             if let Some(frontend) = config.components.get_mut("frontend") {
-                frontend.package_manager = crate::config::PackageManager::Bun;
+                frontend.package_manager = "bun".to_string();
             }
         }
     }
 
+    if Path::new("./cloudbuild.yaml").exists() || Path::new("./app.yaml").exists() {
+        logger::info("GCP deployment config detected (cloudbuild.yaml/app.yaml)");
+        let gcp = config.components.entry("gcp".to_string())
+            .or_insert_with(|| new_detected_component("gcp", "."));
+        gcp.path = ".".to_string();
+    }
+
     Ok(())
 }
+
+/// Build a minimal `ComponentConfig` for a component type discovered by
+/// `detect_project_structure` that isn't one of the built-in defaults.
+fn new_detected_component(component_type: &str, path: &str) -> ComponentConfig {
+    ComponentConfig {
+        name: component_type.to_string(),
+        path: path.to_string(),
+        language: match component_type {
+            "kubernetes" => "yaml".to_string(),
+            "gcp" => "terraform".to_string(),
+            _ => "unknown".to_string(),
+        },
+        package_manager: "none".to_string(),
+        test_command: None,
+        lint_command: None,
+        build_command: None,
+        format_command: None,
+        dev_command: None,
+        depends_on: Vec::new(),
+    }
+}
@@ -0,0 +1,9 @@
+pub mod ci;
+pub mod config;
+pub mod db;
+pub mod deploy;
+pub mod deps;
+pub mod dev;
+pub mod doctor;
+pub mod env;
+pub mod init;
@@ -1,12 +1,18 @@
 use anyhow::{Context, Result};
+use miette::{Diagnostic, NamedSource, SourceSpan};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use thiserror::Error;
 
 /// Main project configuration structure matching config.yaml
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ProjectConfig {
+    /// Schema version, migrated forward automatically on load by
+    /// `utils::config_migration`. Missing/0 means it predates versioning.
+    #[serde(default)]
+    pub version: u32,
     /// Default environment (dev, staging, prod)
     pub env: String,
     /// Development environment configuration
@@ -15,6 +21,104 @@ pub struct ProjectConfig {
     pub staging: Option<EnvConfig>,
     /// Production environment configuration (optional)
     pub prod: Option<EnvConfig>,
+    /// User-defined command shortcuts, e.g. `shipit: "deploy deploy --all"`
+    #[serde(default)]
+    pub aliases: HashMap<String, AliasValue>,
+    /// User-defined or overridden components, merged over the built-in
+    /// backend/frontend/infrastructure set via
+    /// [`ComponentConfig::merge_with_defaults`].
+    #[serde(default)]
+    pub components: HashMap<String, ComponentConfig>,
+    /// Default cap on components run concurrently by `deps install/update/sync
+    /// all` (the CLI `-j`/`--jobs` flag takes precedence). Defaults to the
+    /// number of CPUs when unset.
+    #[serde(default)]
+    pub max_parallel: Option<usize>,
+}
+
+/// An alias expansion: either a single command string or a list of commands
+/// chained with `&&`, mirroring cargo's `[alias]` string/list forms.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(untagged)]
+pub enum AliasValue {
+    Single(String),
+    Chain(Vec<String>),
+}
+
+impl AliasValue {
+    /// Render the alias as a single shell-like command line, joining a chain
+    /// with `&&` so it can be split into argv tokens by the caller.
+    pub fn as_command_line(&self) -> String {
+        match self {
+            AliasValue::Single(cmd) => cmd.clone(),
+            AliasValue::Chain(cmds) => cmds.join(" && "),
+        }
+    }
+}
+
+/// Maximum number of alias-to-alias expansions before we assume a recursive
+/// definition and bail out with an error.
+const MAX_ALIAS_DEPTH: usize = 8;
+
+/// Resolve a config-defined alias against raw CLI args (excluding argv\[0\]),
+/// expanding it into one or more real subcommand invocations when the first
+/// positional token matches an alias and is not itself a built-in subcommand.
+///
+/// An alias like `"dev lint && dev test"` expands into multiple invocations
+/// run in sequence (each getting the alias's own tokens, not the user's
+/// trailing args, since each step is a distinct subcommand). A single-command
+/// alias gets the user's remaining args appended, same as cargo.
+pub fn resolve_aliases(aliases: &HashMap<String, AliasValue>, args: &[String]) -> Result<Vec<Vec<String>>> {
+    let Some(first) = args.first() else {
+        return Ok(vec![args.to_vec()]);
+    };
+
+    if crate::cli::BUILTIN_COMMANDS.contains(&first.as_str()) || !aliases.contains_key(first) {
+        return Ok(vec![args.to_vec()]);
+    }
+
+    let mut current = first.clone();
+    let mut command_line = aliases.get(&current).unwrap().as_command_line();
+    let mut depth = 0;
+
+    // Resolve alias-to-alias chains where the *entire* expansion (not just
+    // its first token) is itself another single-command alias name.
+    while let Some(alias) = aliases.get(command_line.trim()) {
+        depth += 1;
+        if depth > MAX_ALIAS_DEPTH {
+            anyhow::bail!(
+                "Alias '{}' did not resolve after {} expansions; check for an alias that refers to itself",
+                first, MAX_ALIAS_DEPTH
+            );
+        }
+        current = command_line.trim().to_string();
+        command_line = alias.as_command_line();
+    }
+    let _ = current;
+
+    let remaining_user_args = &args[1..];
+    let invocations: Vec<Vec<String>> = command_line
+        .split("&&")
+        .map(|segment| segment.split_whitespace().map(|s| s.to_string()).collect())
+        .collect();
+
+    if invocations.iter().any(|i| i.is_empty()) {
+        anyhow::bail!("Alias '{}' expands to an empty command", first);
+    }
+
+    // Only a single-command alias accepts the user's own trailing args
+    // (e.g. `cwb shipit --env prod` where `shipit = "deploy deploy --all"`).
+    let mut invocations = invocations;
+    if invocations.len() == 1 {
+        invocations[0].extend(remaining_user_args.iter().cloned());
+    } else if !remaining_user_args.is_empty() {
+        anyhow::bail!(
+            "Alias '{}' expands to multiple commands and cannot take extra arguments ({:?})",
+            first, remaining_user_args
+        );
+    }
+
+    Ok(invocations)
 }
 
 /// Environment-specific configuration
@@ -42,6 +146,9 @@ pub struct EnvConfig {
     /// Whether to run CDK Nag checks
     #[serde(default)]
     pub run_cdk_nag: bool,
+    /// Postgres connection string used by `cwb db` commands
+    #[serde(default)]
+    pub database_url: Option<String>,
     /// UI configuration
     pub ui_config: UiConfig,
     /// VPC configuration
@@ -62,6 +169,242 @@ pub struct EnvConfig {
     pub data_config: DataConfig,
     /// Resource tags
     pub tags: Vec<Tag>,
+    /// Outcome notification sink (Slack/Discord/generic webhook)
+    #[serde(default)]
+    pub notifications: Option<NotifierConfig>,
+    /// Remote execution endpoint commands should run against instead of
+    /// the local machine (an SSH build host or a remote Docker daemon)
+    #[serde(default)]
+    pub remote: Option<RemoteConfig>,
+    /// Opt-in destination for failure report uploads (see `utils::telemetry`)
+    #[serde(default)]
+    pub telemetry_config: Option<TelemetryConfig>,
+    /// CloudFormation service role CDK should assume when deploying,
+    /// bootstrapping, or destroying stacks, used when `--role-arn` isn't
+    /// passed on the command line. Lets the executing principal only need
+    /// `sts:AssumeRole` rather than the full set of resource permissions.
+    #[serde(default)]
+    pub cloudformation_role_arn: Option<String>,
+}
+
+/// Where `utils::telemetry` uploads an opt-in failure report when a streamed
+/// command fails. `enable` gates the feature entirely; the user is still
+/// prompted for consent on every upload even when enabled.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TelemetryConfig {
+    #[serde(default)]
+    pub enable: bool,
+    #[serde(flatten)]
+    pub destination: TelemetryDestination,
+}
+
+/// An S3 bucket (uploaded via the `aws` CLI, relying on a bucket lifecycle
+/// rule for the "short object-expiry" behavior) or a plain HTTP endpoint.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum TelemetryDestination {
+    S3 {
+        bucket: String,
+        #[serde(default)]
+        prefix: Option<String>,
+    },
+    Http {
+        url: String,
+    },
+}
+
+/// Per-environment remote execution endpoint for `utils::backend`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum RemoteConfig {
+    /// Run over SSH as `user@host`.
+    Ssh {
+        host: String,
+        user: String,
+        #[serde(default)]
+        key_path: Option<String>,
+        #[serde(default)]
+        port: Option<u16>,
+    },
+    /// Run against a remote Docker daemon, e.g. `tcp://build-host:2376`.
+    Docker {
+        host: String,
+        #[serde(default)]
+        required_api_version: Option<String>,
+    },
+}
+
+impl RemoteConfig {
+    pub fn to_endpoint(&self) -> crate::utils::backend::RemoteEndpoint {
+        match self {
+            RemoteConfig::Ssh { host, user, key_path, port } => crate::utils::backend::RemoteEndpoint::Ssh {
+                host: host.clone(),
+                user: user.clone(),
+                key_path: key_path.as_ref().map(PathBuf::from),
+                port: *port,
+            },
+            RemoteConfig::Docker { host, required_api_version } => crate::utils::backend::RemoteEndpoint::Docker {
+                host: host.clone(),
+                required_api_version: required_api_version.clone(),
+            },
+        }
+    }
+
+    /// Docker API version required by this endpoint, if it's a Docker
+    /// backend that declared one, for `cwb doctor` to verify against.
+    pub fn required_api_version(&self) -> Option<&str> {
+        match self {
+            RemoteConfig::Docker { required_api_version, .. } => required_api_version.as_deref(),
+            RemoteConfig::Ssh { .. } => None,
+        }
+    }
+}
+
+/// Per-environment configuration for the `utils::notifier` subsystem.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifierConfig {
+    /// Incoming webhook URL (Slack, Discord, or a generic HTTP POST endpoint)
+    pub webhook_url: String,
+    /// Which sink format to use when building the payload
+    #[serde(default)]
+    pub sink: NotifierSink,
+    /// Event kinds to send; empty means send all kinds
+    #[serde(default)]
+    pub events: Vec<crate::utils::notifier::EventKind>,
+    /// Only notify on failure, suppressing started/succeeded events
+    #[serde(default)]
+    pub on_failure_only: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifierSink {
+    #[default]
+    Generic,
+    Slack,
+    Discord,
+}
+
+impl NotifierSink {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotifierSink::Generic => "generic",
+            NotifierSink::Slack => "slack",
+            NotifierSink::Discord => "discord",
+        }
+    }
+}
+
+/// A named policy applied to any environment whose name matches
+/// `name_pattern`, resolved via `utils::env_profile`. Profiles are checked
+/// in declaration order and the first match wins, so a catch-all like
+/// `.*` should be declared last.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvironmentProfile {
+    /// Regex matched against the environment name, e.g. `^(prod|production)$`
+    pub name_pattern: String,
+    /// Display color for this environment's marker in `env list`
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Display icon/symbol for this environment's marker in `env list`
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Require typed `confirm_destructive`-style confirmation before
+    /// switching to or running destructive commands against a matching
+    /// environment
+    #[serde(default)]
+    pub protected: bool,
+}
+
+/// Standalone environment registry backing `cwb env`/`cwb init`: arbitrary
+/// named environments (not just dev/staging/prod) with ambient AWS/GCP/
+/// kube-context prefill, persisted to its own `cwb.yaml`. Independent of the
+/// per-deploy `config.yaml`/[`ProjectConfig`] every other command reads,
+/// since it tracks which cloud account an operator is pointed at rather than
+/// how a stack gets built and deployed.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CwbConfig {
+    #[serde(default)]
+    pub project: ProjectMeta,
+    #[serde(default)]
+    pub components: HashMap<String, ComponentConfig>,
+    #[serde(default)]
+    pub environments: HashMap<String, EnvironmentConfig>,
+    #[serde(default)]
+    pub environment_profiles: Vec<EnvironmentProfile>,
+    #[serde(default)]
+    pub current_environment: Option<String>,
+}
+
+impl CwbConfig {
+    /// A fresh registry with the built-in backend/frontend/infrastructure
+    /// components, ready for `cwb init` to fill in project name/type and
+    /// detected structure.
+    pub fn create_default() -> Self {
+        Self {
+            components: ComponentConfig::get_default_components(),
+            ..Default::default()
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+        serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse config file: {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let serialized = serde_yaml::to_string(self).context("Failed to serialize configuration")?;
+        fs::write(path.as_ref(), serialized)
+            .with_context(|| format!("Failed to write config file: {}", path.as_ref().display()))?;
+        Ok(())
+    }
+}
+
+/// Project identity recorded by `cwb init`.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct ProjectMeta {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub r#type: String,
+}
+
+/// One named environment in a [`CwbConfig`] registry — lighter weight than
+/// [`EnvConfig`] (no CDK/infra fields), just enough to remember which cloud
+/// account/region/cluster an operator is pointed at.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvironmentConfig {
+    pub aws_region: String,
+    #[serde(default)]
+    pub aws_profile: Option<String>,
+    #[serde(default)]
+    pub aws_account_id: Option<String>,
+    #[serde(default)]
+    pub gcp_project: Option<String>,
+    #[serde(default)]
+    pub gcp_region: Option<String>,
+    #[serde(default)]
+    pub kubernetes: Option<KubernetesConfig>,
+    #[serde(default)]
+    pub variables: Option<HashMap<String, String>>,
+}
+
+/// The kube context an environment is bound to (see `utils::kubeconfig`).
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct KubernetesConfig {
+    pub context: String,
+    #[serde(default)]
+    pub cluster: Option<String>,
+    #[serde(default)]
+    pub namespace: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -381,6 +724,36 @@ pub struct Tag {
     pub value: String,
 }
 
+/// Span-highlighted diagnostic for a malformed `config.yaml`, built from
+/// `serde_yaml::Error::location()` so a typo points at the offending text
+/// instead of just a generic "failed to parse" message.
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to parse {path}")]
+#[diagnostic(code(config::parse))]
+pub struct ConfigParseError {
+    path: String,
+    #[source_code]
+    src: NamedSource<String>,
+    #[label("{reason}")]
+    span: SourceSpan,
+    reason: String,
+    #[help]
+    help: String,
+}
+
+/// Convert a `serde_yaml::Location`'s 1-indexed line/column into a byte
+/// offset into `content`, by summing the length of every preceding line.
+fn location_to_offset(content: &str, location: serde_yaml::Location) -> usize {
+    let mut offset = 0;
+    for (i, line) in content.lines().enumerate() {
+        if i + 1 == location.line() {
+            return offset + location.column().saturating_sub(1);
+        }
+        offset += line.len() + 1;
+    }
+    offset
+}
+
 impl ProjectConfig {
     /// Load project configuration from config.yaml file
     pub fn load<P: AsRef<Path>>(config_path: P) -> Result<Self> {
@@ -393,7 +766,27 @@ impl ProjectConfig {
         let content = fs::read_to_string(path)
             .with_context(|| format!("Failed to read config file: {}", path.display()))?;
 
-        let config: ProjectConfig = serde_yaml::from_str(&content)
+        let mut value: serde_yaml::Value = serde_yaml::from_str(&content).map_err(|err| {
+            let offset = err
+                .location()
+                .map(|loc| location_to_offset(&content, loc))
+                .unwrap_or(0);
+            ConfigParseError {
+                path: path.display().to_string(),
+                src: NamedSource::new(path.display().to_string(), content.clone()),
+                span: (offset, 1).into(),
+                reason: err.to_string(),
+                help: "Check the YAML syntax at the highlighted location — common culprits are \
+                       missing colons, bad indentation, or an unquoted value containing a colon."
+                    .to_string(),
+            }
+        })?;
+
+        if crate::utils::config_migration::migrate(&mut value)? {
+            crate::utils::config_migration::write_migrated(path, &content, &value)?;
+        }
+
+        let config: ProjectConfig = serde_yaml::from_value(value)
             .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
 
         Ok(config)
@@ -427,6 +820,126 @@ impl ProjectConfig {
         }
         envs
     }
+
+    /// Resolve this project's components: built-in defaults with the
+    /// config.yaml `components` section merged over them by name.
+    pub fn components(&self) -> HashMap<String, ComponentConfig> {
+        ComponentConfig::merge_with_defaults(&self.components)
+    }
+
+    /// Check semantic invariants across every configured environment that
+    /// serde deserialization can't catch (valid values, cross-field
+    /// dependencies), accumulating every problem instead of bailing on the
+    /// first one so a user fixes their config in a single pass.
+    pub fn validate(&self) -> Vec<ConfigError> {
+        let mut errors = self.dev.validate("dev");
+        if let Some(staging) = &self.staging {
+            errors.extend(staging.validate("staging"));
+        }
+        if let Some(prod) = &self.prod {
+            errors.extend(prod.validate("prod"));
+        }
+        errors
+    }
+}
+
+/// One semantic problem found by [`ProjectConfig::validate`]: which
+/// environment it's in, the dotted field path, a human-readable message,
+/// and whether it's a hard error or just a warning worth surfacing.
+#[derive(Debug, Clone)]
+pub struct ConfigError {
+    pub env: String,
+    pub field: String,
+    pub message: String,
+    pub important: bool,
+}
+
+impl ConfigError {
+    fn new(env: &str, field: &str, message: impl Into<String>, important: bool) -> Self {
+        Self {
+            env: env.to_string(),
+            field: field.to_string(),
+            message: message.into(),
+            important,
+        }
+    }
+}
+
+impl EnvConfig {
+    /// Validate this environment's semantic invariants, returning every
+    /// problem found rather than stopping at the first one.
+    pub fn validate(&self, env: &str) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+
+        if self.account_number.len() != 12 || !self.account_number.chars().all(|c| c.is_ascii_digit()) {
+            errors.push(ConfigError::new(
+                env,
+                "account_number",
+                format!("'{}' must be exactly 12 digits", self.account_number),
+                true,
+            ));
+        }
+
+        if regex::Regex::new(r"^[a-z]{2}(-gov)?-[a-z]+-\d$").map(|re| !re.is_match(&self.region)).unwrap_or(true) {
+            errors.push(ConfigError::new(
+                env,
+                "region",
+                format!("'{}' doesn't look like a valid AWS region (e.g. us-east-1)", self.region),
+                true,
+            ));
+        }
+
+        if self.load_balancer_config.alb_placement != "public" && self.load_balancer_config.alb_placement != "private" {
+            errors.push(ConfigError::new(
+                env,
+                "load_balancer_config.alb_placement",
+                format!("'{}' must be one of: public, private", self.load_balancer_config.alb_placement),
+                true,
+            ));
+        }
+
+        if !["destroy", "retain", "snapshot"].contains(&self.removal_policy.as_str()) {
+            errors.push(ConfigError::new(
+                env,
+                "removal_policy",
+                format!("'{}' must be one of: destroy, retain, snapshot", self.removal_policy),
+                true,
+            ));
+        }
+
+        if self.load_balancer_config.alb_placement == "public" && self.load_balancer_config.ssl_certificate_arn.is_none() {
+            errors.push(ConfigError::new(
+                env,
+                "load_balancer_config.ssl_certificate_arn",
+                "required when load_balancer_config.alb_placement is 'public' so the ALB can terminate TLS",
+                true,
+            ));
+        }
+
+        if self.alarm_config.enable {
+            for address in self.alarm_config.email_addresses.iter().flatten() {
+                if !address.contains('@') || address.starts_with('@') || address.ends_with('@') {
+                    errors.push(ConfigError::new(
+                        env,
+                        "alarm_config.email_addresses",
+                        format!("'{}' is not a well-formed email address", address),
+                        true,
+                    ));
+                }
+            }
+        }
+
+        if self.data_config.bedrock_knowledge_base_enabled && !self.data_config.open_search_enabled {
+            errors.push(ConfigError::new(
+                env,
+                "data_config.bedrock_knowledge_base_enabled",
+                "requires data_config.open_search_enabled to be true",
+                true,
+            ));
+        }
+
+        errors
+    }
 }
 
 /// Find the project configuration file by searching up the directory tree
@@ -458,19 +971,58 @@ pub fn find_config_file() -> Result<PathBuf> {
     anyhow::bail!("No config.yaml file found. Make sure you're in the project directory.")
 }
 
-/// Component configuration for development commands
-/// This maps the project structure to development tools
-#[derive(Debug, Clone)]
+/// Find the `cwb env`/`cwb init` environment registry by searching up the
+/// directory tree, mirroring [`find_config_file`] but for `cwb.yaml` rather
+/// than `config.yaml` — the two are independent files.
+pub fn find_cwb_config_file() -> Result<PathBuf> {
+    let current_dir = std::env::current_dir()
+        .context("Failed to get current directory")?;
+
+    let mut dir = current_dir.as_path();
+
+    loop {
+        let config_path = dir.join("cwb.yaml");
+        if config_path.exists() {
+            return Ok(config_path);
+        }
+
+        match dir.parent() {
+            Some(parent) => dir = parent,
+            None => break,
+        }
+    }
+
+    anyhow::bail!("No cwb.yaml file found. Run 'cwb init' to create one.")
+}
+
+/// Component configuration for development commands.
+/// This maps the project structure to development tools. Built-in defaults
+/// (backend/frontend/infrastructure) can be overridden or extended by a
+/// `components` section in config.yaml, keyed the same way cargo aliases are.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ComponentConfig {
+    /// Always set from the `components` map key; any value given in config.yaml is replaced.
+    #[serde(default)]
     pub name: String,
     pub path: String,
     pub language: String,
     pub package_manager: String,
+    #[serde(default)]
     pub test_command: Option<String>,
+    #[serde(default)]
     pub lint_command: Option<String>,
+    #[serde(default)]
     pub build_command: Option<String>,
+    #[serde(default)]
     pub format_command: Option<String>,
+    #[serde(default)]
     pub dev_command: Option<String>,
+    /// Other component names that must build/test/lint successfully before
+    /// this one does when running `dev build|test|lint all`. Empty by
+    /// default, meaning no ordering constraint.
+    #[serde(default)]
+    pub depends_on: Vec<String>,
 }
 
 impl ComponentConfig {
@@ -488,6 +1040,7 @@ impl ComponentConfig {
             build_command: None,
             format_command: Some("ruff format".to_string()),
             dev_command: Some("python -m app.api.main".to_string()),
+            depends_on: Vec::new(),
         });
 
         components.insert("frontend".to_string(), ComponentConfig {
@@ -500,6 +1053,7 @@ impl ComponentConfig {
             build_command: Some("npm run build".to_string()),
             format_command: Some("npm run format".to_string()),
             dev_command: Some("npm run dev".to_string()),
+            depends_on: Vec::new(),
         });
 
         components.insert("infrastructure".to_string(), ComponentConfig {
@@ -512,8 +1066,24 @@ impl ComponentConfig {
             build_command: Some("npm run build".to_string()),
             format_command: None,
             dev_command: None,
+            // CDK synth packages compiled backend/frontend build output, so
+            // it must run after both have built successfully.
+            depends_on: vec!["backend".to_string(), "frontend".to_string()],
         });
 
         components
     }
+
+    /// Merge a user's `components` section over the built-ins: entries that
+    /// share a name with a built-in override it entirely, new names are
+    /// added. Like cargo's alias lookup, user config always wins by name.
+    pub fn merge_with_defaults(overrides: &HashMap<String, ComponentConfig>) -> HashMap<String, ComponentConfig> {
+        let mut components = Self::get_default_components();
+        for (name, component) in overrides {
+            let mut component = component.clone();
+            component.name = name.clone();
+            components.insert(name.clone(), component);
+        }
+        components
+    }
 }
@@ -6,22 +6,74 @@ mod utils;
 use anyhow::Result;
 use clap::Parser;
 use cli::{Cli, Commands};
-use config::{ProjectConfig, ComponentConfig, find_config_file};
+use config::{ProjectConfig, find_config_file, resolve_aliases};
 use utils::logger;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let raw_args: Vec<String> = std::env::args().collect();
+    let invocations = expand_aliases(&raw_args[1..]);
 
-    if let Err(e) = run(cli).await {
-        logger::error(&format!("Command failed: {}", e));
-        std::process::exit(1);
+    for invocation in invocations {
+        let mut argv = vec![raw_args[0].clone()];
+        argv.extend(invocation);
+
+        let cli = match Cli::try_parse_from(&argv) {
+            Ok(cli) => cli,
+            Err(e) => {
+                e.exit();
+            }
+        };
+
+        if let Err(e) = run(cli).await {
+            match e.downcast::<config::ConfigParseError>() {
+                Ok(diagnostic) => eprintln!("{:?}", miette::Report::new(diagnostic)),
+                Err(e) => logger::error(&format!("Command failed: {}", e)),
+            }
+            std::process::exit(1);
+        }
     }
 
     Ok(())
 }
 
+/// Best-effort config-defined alias expansion, run before clap ever sees the
+/// args. Any failure to locate/parse a config just means no aliases are
+/// defined yet, so we fall back to passing the raw args through unchanged.
+fn expand_aliases(args: &[String]) -> Vec<Vec<String>> {
+    let config_path = match find_config_file() {
+        Ok(path) => path,
+        Err(_) => return vec![args.to_vec()],
+    };
+
+    let project_config = match ProjectConfig::load(&config_path) {
+        Ok(config) => config,
+        Err(_) => return vec![args.to_vec()],
+    };
+
+    match resolve_aliases(&project_config.aliases, args) {
+        Ok(invocations) => invocations,
+        Err(e) => {
+            logger::error(&format!("Alias resolution failed: {}", e));
+            std::process::exit(1);
+        }
+    }
+}
+
 async fn run(cli: Cli) -> Result<()> {
+    // `init`/`env` manage their own `cwb.yaml` registry rather than the
+    // per-deploy `config.yaml`, so they must run before we unconditionally
+    // require a project configuration to already exist below.
+    match &cli.command {
+        Commands::Init { name, project_type } => {
+            return commands::init::handle_init(name.clone(), project_type.clone(), &cli).await;
+        }
+        Commands::Env(env_cmd) => {
+            return commands::env::handle_env(env_cmd.clone(), &cli).await;
+        }
+        _ => {}
+    }
+
     // Load project configuration
     let config_path = match &cli.config {
         Some(path) => std::path::PathBuf::from(path),
@@ -38,7 +90,7 @@ async fn run(cli: Cli) -> Result<()> {
     let env_config = project_config.get_env_config(env)?;
 
     // Get component configurations for development commands
-    let components = ComponentConfig::get_default_components();
+    let components = project_config.components();
 
     match &cli.command {
         Commands::Version => {
@@ -58,10 +110,16 @@ async fn run(cli: Cli) -> Result<()> {
             commands::deploy::handle_deploy(deploy_cmd.clone(), &project_config, env_config, &cli).await
         }
         Commands::Dev(dev_cmd) => {
-            commands::dev::handle_dev(dev_cmd.clone(), &components, &cli).await
+            commands::dev::handle_dev(dev_cmd.clone(), &components, env_config, &cli).await
         }
         Commands::Deps(deps_cmd) => {
-            commands::deps::handle_deps(deps_cmd.clone(), &components, &cli).await
+            commands::deps::handle_deps(deps_cmd.clone(), &components, project_config.max_parallel, &cli).await
+        }
+        Commands::Ci(ci_cmd) => {
+            commands::ci::handle_ci(ci_cmd.clone(), env_config, &cli).await
+        }
+        Commands::Db(db_cmd) => {
+            commands::db::handle_db(db_cmd.clone(), env_config, &cli).await
         }
         _ => {
             logger::warning("Command not yet implemented");
@@ -0,0 +1,140 @@
+//! Minimal reader for the AWS shared config/credentials INI files, plus
+//! detection of the ambient profile/region a shell wrapper has already set.
+//! Used to prefill `cwb env create`/`env switch` instead of prompting blind
+//! for values the AWS CLI and its wrappers already have on hand.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Resolved fields pulled from a profile's `[profile <name>]`/`[default]`
+/// section in `~/.aws/config` and its matching `[<name>]` section in
+/// `~/.aws/credentials`.
+#[derive(Debug, Clone, Default)]
+pub struct ProfileSettings {
+    pub region: Option<String>,
+    pub account_id: Option<String>,
+}
+
+/// Path to the AWS shared config file, honoring `AWS_CONFIG_FILE`.
+fn config_file_path() -> Option<PathBuf> {
+    if let Ok(path) = std::env::var("AWS_CONFIG_FILE") {
+        return Some(PathBuf::from(path));
+    }
+    dirs_home().map(|home| home.join(".aws").join("config"))
+}
+
+/// Path to the AWS shared credentials file, honoring
+/// `AWS_SHARED_CREDENTIALS_FILE`.
+fn credentials_file_path() -> Option<PathBuf> {
+    if let Ok(path) = std::env::var("AWS_SHARED_CREDENTIALS_FILE") {
+        return Some(PathBuf::from(path));
+    }
+    dirs_home().map(|home| home.join(".aws").join("credentials"))
+}
+
+fn dirs_home() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(PathBuf::from)
+}
+
+/// Parse an INI-style file into `section name -> (key -> value)`, lower-
+/// casing keys the way the AWS CLI does. Comments (`#`/`;`) and blank lines
+/// are skipped; unknown syntax is ignored rather than treated as an error,
+/// since this is best-effort prefilling, not config validation.
+fn parse_ini(content: &str) -> HashMap<String, HashMap<String, String>> {
+    let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+    let mut current = String::new();
+
+    for raw_line in content.lines() {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            current = line[1..line.len() - 1].trim().to_string();
+            sections.entry(current.clone()).or_default();
+            continue;
+        }
+
+        if let Some((key, value)) = line.split_once('=') {
+            if current.is_empty() {
+                continue;
+            }
+            sections
+                .entry(current.clone())
+                .or_default()
+                .insert(key.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    sections
+}
+
+/// Look up `profile` in `~/.aws/config` (as `[profile <name>]`, or
+/// `[default]` when `profile` is `"default"`) and in `~/.aws/credentials`
+/// (as the bare `[<name>]`), merging whatever fields each side has.
+pub fn resolve_profile(profile: &str) -> ProfileSettings {
+    let mut settings = ProfileSettings::default();
+
+    if let Some(path) = config_file_path() {
+        if let Ok(content) = fs::read_to_string(&path) {
+            let sections = parse_ini(&content);
+            let section_name = if profile == "default" {
+                "default".to_string()
+            } else {
+                format!("profile {}", profile)
+            };
+
+            if let Some(fields) = sections.get(&section_name) {
+                apply_fields(&mut settings, fields);
+            }
+        }
+    }
+
+    if let Some(path) = credentials_file_path() {
+        if let Ok(content) = fs::read_to_string(&path) {
+            let sections = parse_ini(&content);
+            if let Some(fields) = sections.get(profile) {
+                apply_fields(&mut settings, fields);
+            }
+        }
+    }
+
+    settings
+}
+
+/// Detect the AWS profile the surrounding shell has already assumed, the
+/// way wrapper tools expose it, checked in priority order: `AWSU_PROFILE`
+/// (awsu), `AWS_VAULT` (aws-vault), `AWSUME_PROFILE` (awsume), then the
+/// plain `AWS_PROFILE`.
+pub fn ambient_profile() -> Option<String> {
+    ["AWSU_PROFILE", "AWS_VAULT", "AWSUME_PROFILE", "AWS_PROFILE"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()))
+}
+
+/// Detect the AWS region the surrounding shell/tooling has set, checking
+/// `AWS_REGION` then `AWS_DEFAULT_REGION`.
+pub fn ambient_region() -> Option<String> {
+    ["AWS_REGION", "AWS_DEFAULT_REGION"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()))
+}
+
+fn apply_fields(settings: &mut ProfileSettings, fields: &HashMap<String, String>) {
+    if let Some(region) = fields.get("region") {
+        settings.region = Some(region.clone());
+    }
+    if let Some(account_id) = fields.get("sso_account_id") {
+        settings.account_id = Some(account_id.clone());
+    } else if let Some(role_arn) = fields.get("role_arn").and_then(|arn| account_id_from_arn(arn)) {
+        settings.account_id = Some(role_arn);
+    }
+}
+
+/// Pull the account id out of a role ARN, e.g.
+/// `arn:aws:iam::123456789012:role/Example` -> `123456789012`.
+fn account_id_from_arn(arn: &str) -> Option<String> {
+    arn.split(':').nth(4).map(|s| s.to_string())
+}
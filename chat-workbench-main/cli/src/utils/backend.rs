@@ -0,0 +1,154 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::config::EnvConfig;
+
+/// A logical command translated into the concrete binary/args/envs/working
+/// directory that must actually be spawned *locally* to realize it against a
+/// given backend. Local execution is the identity transform; remote execution
+/// wraps the command in `ssh` or points it at a remote Docker daemon.
+#[derive(Debug, Clone)]
+pub struct ResolvedCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    pub envs: Vec<(String, String)>,
+    pub working_dir: Option<PathBuf>,
+}
+
+/// Where a command actually runs. `CommandExecutor` spawns whatever a
+/// backend resolves a logical `(cmd, args, working_dir)` into, so callers
+/// like `cwb deploy`/`cwb dev build` don't need to know whether they're
+/// targeting the local machine or a remote build host.
+pub trait ExecBackend: Send + Sync {
+    fn resolve(&self, cmd: &str, args: &[&str], working_dir: Option<&Path>) -> ResolvedCommand;
+
+    fn check_command_exists(&self, cmd: &str) -> bool;
+
+    /// Human-readable form of what would actually run, for `--dry-run`.
+    fn describe(&self, cmd: &str, args: &[&str], working_dir: Option<&Path>) -> String {
+        let resolved = self.resolve(cmd, args, working_dir);
+        format!("{} {}", resolved.program, resolved.args.join(" "))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LocalBackend;
+
+impl ExecBackend for LocalBackend {
+    fn resolve(&self, cmd: &str, args: &[&str], working_dir: Option<&Path>) -> ResolvedCommand {
+        ResolvedCommand {
+            program: cmd.to_string(),
+            args: args.iter().map(|s| s.to_string()).collect(),
+            envs: Vec::new(),
+            working_dir: working_dir.map(PathBuf::from),
+        }
+    }
+
+    fn check_command_exists(&self, cmd: &str) -> bool {
+        std::process::Command::new("which")
+            .arg(cmd)
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+}
+
+/// How a `RemoteBackend` reaches its endpoint.
+#[derive(Debug, Clone)]
+pub enum RemoteEndpoint {
+    /// Run over SSH as `user@host`, optionally pinned to a key and port.
+    Ssh {
+        host: String,
+        user: String,
+        key_path: Option<PathBuf>,
+        port: Option<u16>,
+    },
+    /// Run against a remote Docker daemon by setting `DOCKER_HOST`; only
+    /// meaningful for commands that are themselves `docker`/`cdk`-style
+    /// Docker API clients.
+    Docker {
+        host: String,
+        required_api_version: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoteBackend {
+    pub endpoint: RemoteEndpoint,
+}
+
+impl RemoteBackend {
+    pub fn new(endpoint: RemoteEndpoint) -> Self {
+        Self { endpoint }
+    }
+}
+
+impl ExecBackend for RemoteBackend {
+    fn resolve(&self, cmd: &str, args: &[&str], working_dir: Option<&Path>) -> ResolvedCommand {
+        match &self.endpoint {
+            RemoteEndpoint::Ssh { host, user, key_path, port } => {
+                let mut remote_parts = Vec::new();
+                if let Some(dir) = working_dir {
+                    remote_parts.push(format!("cd {}", shell_quote(&dir.display().to_string())));
+                }
+                let command_line = std::iter::once(cmd)
+                    .chain(args.iter().copied())
+                    .map(shell_quote)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                remote_parts.push(command_line);
+
+                let mut ssh_args = Vec::new();
+                if let Some(key) = key_path {
+                    ssh_args.push("-i".to_string());
+                    ssh_args.push(key.display().to_string());
+                }
+                if let Some(p) = port {
+                    ssh_args.push("-p".to_string());
+                    ssh_args.push(p.to_string());
+                }
+                ssh_args.push(format!("{}@{}", user, host));
+                ssh_args.push(remote_parts.join(" && "));
+
+                ResolvedCommand {
+                    program: "ssh".to_string(),
+                    args: ssh_args,
+                    envs: Vec::new(),
+                    // The remote `cd` is embedded in the ssh command line; the
+                    // local `ssh` client itself has no reason to change directory.
+                    working_dir: None,
+                }
+            }
+            RemoteEndpoint::Docker { host, .. } => ResolvedCommand {
+                program: cmd.to_string(),
+                args: args.iter().map(|s| s.to_string()).collect(),
+                envs: vec![("DOCKER_HOST".to_string(), host.clone())],
+                working_dir: working_dir.map(PathBuf::from),
+            },
+        }
+    }
+
+    fn check_command_exists(&self, cmd: &str) -> bool {
+        let resolved = self.resolve("which", &[cmd], None);
+        let mut command = std::process::Command::new(&resolved.program);
+        command.args(&resolved.args);
+        for (key, value) in &resolved.envs {
+            command.env(key, value);
+        }
+        command.output().map(|output| output.status.success()).unwrap_or(false)
+    }
+}
+
+/// Single-quote a token for inclusion in a remote shell command line.
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+/// Build the backend an environment's commands should run against: remote
+/// when `EnvConfig.remote` names an endpoint, local otherwise.
+pub fn backend_for_env(env_config: &EnvConfig) -> Arc<dyn ExecBackend> {
+    match &env_config.remote {
+        Some(remote) => Arc::new(RemoteBackend::new(remote.to_endpoint())),
+        None => Arc::new(LocalBackend),
+    }
+}
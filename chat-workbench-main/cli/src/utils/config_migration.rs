@@ -0,0 +1,95 @@
+//! Schema-version migrations for config.yaml, applied to the raw
+//! `serde_yaml::Value` before typed deserialization (mirroring
+//! `commands::db`'s ordered-migration-steps model) so a rename or new
+//! required field in `ProjectConfig` doesn't silently break configs written
+//! against an older version of this tool.
+
+use anyhow::{Context, Result};
+use serde_yaml::Value;
+
+use super::logger;
+
+/// Current config.yaml schema version. Bump this and append a migration
+/// step below whenever a breaking rename/addition is made to `ProjectConfig`.
+pub const CURRENT_VERSION: u32 = 1;
+
+/// One schema migration step, rewriting the raw YAML value from
+/// `from_version` to `from_version + 1`.
+struct Migration {
+    from_version: u32,
+    description: &'static str,
+    apply: fn(&mut Value),
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from_version: 0,
+        description: "Add an explicit `version` field (no field changes)",
+        apply: |_| {},
+    },
+];
+
+/// Apply every migration needed to bring `value` from its on-disk `version`
+/// field (missing means 0, i.e. predates schema versioning) up to
+/// `CURRENT_VERSION`, logging each step. Returns whether anything changed,
+/// so the caller knows whether the file needs to be rewritten.
+pub fn migrate(value: &mut Value) -> Result<bool> {
+    let mut version = value
+        .get("version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as u32;
+
+    if version > CURRENT_VERSION {
+        anyhow::bail!(
+            "config.yaml version {} is newer than this build of cwb supports ({}); upgrade cwb",
+            version, CURRENT_VERSION
+        );
+    }
+
+    let mut changed = false;
+    while version < CURRENT_VERSION {
+        let step = MIGRATIONS
+            .iter()
+            .find(|m| m.from_version == version)
+            .ok_or_else(|| anyhow::anyhow!("No migration path from config.yaml version {} to {}", version, CURRENT_VERSION))?;
+
+        logger::info(&format!(
+            "Migrating config.yaml from version {} to {}: {}",
+            version, version + 1, step.description
+        ));
+        (step.apply)(value);
+        version += 1;
+        changed = true;
+    }
+
+    if changed {
+        set_version(value, version)?;
+    }
+
+    Ok(changed)
+}
+
+fn set_version(value: &mut Value, version: u32) -> Result<()> {
+    let map = value.as_mapping_mut().context("config.yaml root is not a mapping")?;
+    map.insert(Value::String("version".to_string()), Value::Number(version.into()));
+    Ok(())
+}
+
+/// Persist a migrated config, first backing up the untouched original to
+/// `<path>.bak` (preserving comments is out of scope, hence the backup).
+pub fn write_migrated(path: &std::path::Path, original_content: &str, value: &Value) -> Result<()> {
+    let backup_path = std::path::PathBuf::from(format!("{}.bak", path.display()));
+    std::fs::write(&backup_path, original_content)
+        .with_context(|| format!("Failed to write config backup: {}", backup_path.display()))?;
+
+    let serialized = serde_yaml::to_string(value).context("Failed to serialize migrated config")?;
+    std::fs::write(path, serialized)
+        .with_context(|| format!("Failed to write migrated config: {}", path.display()))?;
+
+    logger::success(&format!(
+        "Migrated config.yaml to version {} (original backed up to {})",
+        CURRENT_VERSION, backup_path.display()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+//! Cross-component coverage aggregation for `dev test --coverage`, modeled
+//! on Deno's `CoverageCollector`: instrument each component's test command
+//! for its package manager, then once every component has run, read back
+//! its emitted LCOV file, rewrite `SF:` paths to be repo-root-relative, and
+//! concatenate them into one combined `lcov.info` with a per-component and
+//! total line/branch coverage summary.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::config::ComponentConfig;
+use super::logger;
+
+/// Relative path (under a component's own `path`) that our coverage flags
+/// ask pytest/cargo-llvm-cov to write their LCOV report to. Jest writes its
+/// own default `coverage/lcov.info`, so it isn't routed through this.
+const LCOV_FILE: &str = "coverage.lcov";
+
+/// Extra flags that make a component's test command emit an LCOV report,
+/// or `None` if we don't know how to instrument this package manager.
+/// `cmd_parts` is the command split on whitespace, including the program
+/// name, so callers can sniff the actual test runner (e.g. `pytest`).
+pub fn coverage_args(comp_config: &ComponentConfig, cmd_parts: &[String]) -> Option<Vec<String>> {
+    match comp_config.package_manager.as_str() {
+        "npm" | "yarn" | "pnpm" => Some(vec!["--coverage".to_string(), "--coverageReporters=lcov".to_string()]),
+        "uv" | "pip" if cmd_parts.iter().any(|p| p.contains("pytest")) => {
+            Some(vec!["--cov".to_string(), format!("--cov-report=lcov:{}", LCOV_FILE)])
+        }
+        _ => None,
+    }
+}
+
+/// Rewrite a `cargo test` command into the equivalent `cargo-llvm-cov`
+/// invocation (which runs the tests itself and emits LCOV), keeping any
+/// args beyond `test` (e.g. `--all-features`). `None` if `cmd_parts` isn't
+/// actually a `cargo test` invocation.
+pub fn cargo_llvm_cov_parts(cmd_parts: &[String]) -> Option<Vec<String>> {
+    if cmd_parts.first().map(String::as_str) != Some("cargo") || cmd_parts.get(1).map(String::as_str) != Some("test") {
+        return None;
+    }
+
+    let mut parts = vec!["cargo".to_string(), "llvm-cov".to_string(), "--lcov".to_string(), "--output-path".to_string(), LCOV_FILE.to_string()];
+    parts.extend(cmd_parts[2..].iter().cloned());
+    Some(parts)
+}
+
+/// Where a component's LCOV report ends up once `--coverage` is set, or
+/// `None` if we don't instrument this package manager for coverage.
+pub fn report_path(comp_config: &ComponentConfig) -> Option<PathBuf> {
+    let comp_path = Path::new(&comp_config.path);
+    match comp_config.package_manager.as_str() {
+        "npm" | "yarn" | "pnpm" => Some(comp_path.join("coverage").join("lcov.info")),
+        "uv" | "pip" | "cargo" => Some(comp_path.join(LCOV_FILE)),
+        _ => None,
+    }
+}
+
+/// Summed line/branch totals for one component (or, once merged, for a
+/// whole run).
+#[derive(Debug, Clone, Default)]
+pub struct CoverageSummary {
+    pub lines_found: u64,
+    pub lines_hit: u64,
+    pub branches_found: u64,
+    pub branches_hit: u64,
+}
+
+impl CoverageSummary {
+    pub fn line_pct(&self) -> f64 {
+        if self.lines_found == 0 { 100.0 } else { self.lines_hit as f64 / self.lines_found as f64 * 100.0 }
+    }
+
+    pub fn branch_pct(&self) -> f64 {
+        if self.branches_found == 0 { 100.0 } else { self.branches_hit as f64 / self.branches_found as f64 * 100.0 }
+    }
+
+    fn add(&mut self, other: &CoverageSummary) {
+        self.lines_found += other.lines_found;
+        self.lines_hit += other.lines_hit;
+        self.branches_found += other.branches_found;
+        self.branches_hit += other.branches_hit;
+    }
+}
+
+/// Read one component's LCOV file, rewriting its `SF:` paths (recorded
+/// relative to the directory the tool ran in, i.e. `comp_config.path`) to be
+/// relative to the repo root instead, so the merged report's paths resolve
+/// regardless of which component they came from.
+fn read_component_lcov(comp_config: &ComponentConfig, path: &Path) -> Result<(String, CoverageSummary)> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read coverage report: {}", path.display()))?;
+
+    let mut summary = CoverageSummary::default();
+    let mut rewritten = String::new();
+
+    for line in raw.lines() {
+        if let Some(rel) = line.strip_prefix("SF:") {
+            let rebased = Path::new(&comp_config.path).join(rel);
+            rewritten.push_str("SF:");
+            rewritten.push_str(&rebased.to_string_lossy());
+        } else {
+            rewritten.push_str(line);
+            if let Some(n) = line.strip_prefix("LF:") {
+                summary.lines_found += n.trim().parse().unwrap_or(0);
+            } else if let Some(n) = line.strip_prefix("LH:") {
+                summary.lines_hit += n.trim().parse().unwrap_or(0);
+            } else if let Some(n) = line.strip_prefix("BRF:") {
+                summary.branches_found += n.trim().parse().unwrap_or(0);
+            } else if let Some(n) = line.strip_prefix("BRH:") {
+                summary.branches_hit += n.trim().parse().unwrap_or(0);
+            }
+        }
+        rewritten.push('\n');
+    }
+
+    Ok((rewritten, summary))
+}
+
+/// Merge every component's discovered coverage report into one combined
+/// LCOV file at `dest`, logging a per-component line/branch summary as it
+/// goes. Components with no report on disk (coverage wasn't requested for
+/// their package manager, or the run never got that far) are skipped with a
+/// warning. Returns the combined total.
+pub fn merge_reports(components: &[(ComponentConfig, PathBuf)], dest: &Path) -> Result<CoverageSummary> {
+    let mut combined = String::new();
+    let mut total = CoverageSummary::default();
+
+    for (comp_config, path) in components {
+        if !path.exists() {
+            logger::warning(&format!("No coverage report found for component '{}' at {}", comp_config.name, path.display()));
+            continue;
+        }
+
+        let (rewritten, summary) = read_component_lcov(comp_config, path)?;
+        logger::info(&format!(
+            "Coverage for {}: {:.1}% lines ({}/{}), {:.1}% branches ({}/{})",
+            comp_config.name, summary.line_pct(), summary.lines_hit, summary.lines_found,
+            summary.branch_pct(), summary.branches_hit, summary.branches_found,
+        ));
+        total.add(&summary);
+        combined.push_str(&rewritten);
+    }
+
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create coverage directory: {}", parent.display()))?;
+    }
+    std::fs::write(dest, combined)
+        .with_context(|| format!("Failed to write combined coverage report: {}", dest.display()))?;
+
+    logger::success(&format!(
+        "Total coverage: {:.1}% lines ({}/{}), {:.1}% branches ({}/{}) -> {}",
+        total.line_pct(), total.lines_hit, total.lines_found,
+        total.branch_pct(), total.branches_hit, total.branches_found,
+        dest.display(),
+    ));
+
+    Ok(total)
+}
@@ -0,0 +1,41 @@
+//! Pattern-driven environment policy: matches an environment name against
+//! the user's configured `environment_profiles` (first match wins) and
+//! resolves the display/safety overrides that name should get, replacing
+//! the hard-coded green/white markers and ad-hoc delete guard with a
+//! configurable policy that can cover many environments (`prod`,
+//! `prod-*`, `.*-sandbox`, ...) at once.
+
+use regex::Regex;
+
+use crate::config::EnvironmentProfile;
+
+/// The overrides an environment name picked up from its matching profile,
+/// with safe defaults when no profile matches.
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedPolicy {
+    pub color: Option<String>,
+    pub icon: Option<String>,
+    pub protected: bool,
+}
+
+/// Compile each profile's `name_pattern` in order and apply the overrides
+/// of the first one whose regex matches `env_name`. Profiles with an
+/// invalid pattern are skipped rather than failing the whole lookup, since
+/// a typo in one profile shouldn't block switching to every environment.
+pub fn resolve(env_name: &str, profiles: &[EnvironmentProfile]) -> ResolvedPolicy {
+    for profile in profiles {
+        let Ok(pattern) = Regex::new(&profile.name_pattern) else {
+            continue;
+        };
+
+        if pattern.is_match(env_name) {
+            return ResolvedPolicy {
+                color: profile.color.clone(),
+                icon: profile.icon.clone(),
+                protected: profile.protected,
+            };
+        }
+    }
+
+    ResolvedPolicy::default()
+}
@@ -0,0 +1,84 @@
+//! Minimal reader for the gcloud CLI's active configuration, used to
+//! prefill `cwb env create` with a GCP project/region the same way
+//! `utils::aws_config` does for an AWS profile, so mixed AWS+GCP projects
+//! get the same "confirm, don't retype" workflow.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Fields pulled from gcloud's active named configuration.
+#[derive(Debug, Clone, Default)]
+pub struct ActiveConfig {
+    pub project: Option<String>,
+    pub account: Option<String>,
+    pub region: Option<String>,
+}
+
+/// The gcloud config directory, honoring `CLOUDSDK_CONFIG`, else
+/// `~/.config/gcloud`.
+fn gcloud_config_dir() -> Option<PathBuf> {
+    if let Ok(path) = std::env::var("CLOUDSDK_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config").join("gcloud"))
+}
+
+/// Parse an INI-style file into `section name -> (key -> value)`.
+fn parse_ini(content: &str) -> HashMap<String, HashMap<String, String>> {
+    let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+    let mut current = String::new();
+
+    for raw_line in content.lines() {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            current = line[1..line.len() - 1].trim().to_string();
+            sections.entry(current.clone()).or_default();
+            continue;
+        }
+
+        if let Some((key, value)) = line.split_once('=') {
+            if current.is_empty() {
+                continue;
+            }
+            sections
+                .entry(current.clone())
+                .or_default()
+                .insert(key.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    sections
+}
+
+/// Read `active_config` to get gcloud's active named configuration, then
+/// load `configurations/config_<name>` for its `[core] project`,
+/// `[core] account`, and `[compute] region`.
+pub fn resolve_active_config() -> Option<ActiveConfig> {
+    let dir = gcloud_config_dir()?;
+
+    let active_name = fs::read_to_string(dir.join("active_config")).ok()?;
+    let active_name = active_name.trim();
+    if active_name.is_empty() {
+        return None;
+    }
+
+    let config_path = dir.join("configurations").join(format!("config_{}", active_name));
+    let content = fs::read_to_string(&config_path).ok()?;
+    let sections = parse_ini(&content);
+
+    let mut resolved = ActiveConfig::default();
+    if let Some(core) = sections.get("core") {
+        resolved.project = core.get("project").cloned();
+        resolved.account = core.get("account").cloned();
+    }
+    if let Some(compute) = sections.get("compute") {
+        resolved.region = compute.get("region").cloned();
+    }
+
+    Some(resolved)
+}
@@ -0,0 +1,94 @@
+//! Minimal kubeconfig reader used to resolve the live cluster/namespace an
+//! environment is pointed at, the same way `kubectl config view` would,
+//! without pulling in a full YAML-to-struct kubeconfig model.
+
+use std::fs;
+use std::path::PathBuf;
+
+/// The cluster/user/namespace an environment's `current-context` resolves
+/// to, as read off disk rather than configured by hand.
+#[derive(Debug, Clone, Default)]
+pub struct KubeContext {
+    pub context: String,
+    pub cluster: Option<String>,
+    pub user: Option<String>,
+    pub namespace: Option<String>,
+}
+
+/// The ordered list of kubeconfig files to merge, from `KUBECONFIG` split on
+/// the platform path separator (`:` on Unix, `;` on Windows), falling back
+/// to `~/.kube/config` when unset.
+fn kubeconfig_paths() -> Vec<PathBuf> {
+    if let Ok(raw) = std::env::var("KUBECONFIG") {
+        let paths: Vec<PathBuf> = std::env::split_paths(&raw).filter(|p| !p.as_os_str().is_empty()).collect();
+        if !paths.is_empty() {
+            return paths;
+        }
+    }
+
+    std::env::var_os("HOME")
+        .map(|home| vec![PathBuf::from(home).join(".kube").join("config")])
+        .unwrap_or_default()
+}
+
+/// Resolve the active context by merging every file in `KUBECONFIG` the way
+/// `kubectl` does: `current-context`, the context's definition, and the
+/// namespace frequently live in different files, so this is a two-pass
+/// scan rather than a single-file read.
+///
+/// Pass one finds the first non-empty `current-context` across all files,
+/// in order. Pass two scans all files again for a `contexts[]` entry whose
+/// `name` matches, pulling `cluster`/`user`/`namespace` from whichever file
+/// defines it.
+pub fn resolve_current_context() -> Option<KubeContext> {
+    let paths = kubeconfig_paths();
+    let docs: Vec<serde_yaml::Value> = paths
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|content| serde_yaml::from_str(&content).ok())
+        .collect();
+
+    let context_name = docs
+        .iter()
+        .find_map(|doc| doc.get("current-context").and_then(|v| v.as_str()).filter(|s| !s.is_empty()))?
+        .to_string();
+
+    let mut resolved = KubeContext {
+        context: context_name.clone(),
+        ..Default::default()
+    };
+
+    for doc in &docs {
+        let Some(contexts) = doc.get("contexts").and_then(|v| v.as_sequence()) else {
+            continue;
+        };
+
+        for entry in contexts {
+            let Some(name) = entry.get("name").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            if name != context_name {
+                continue;
+            }
+            let Some(ctx) = entry.get("context") else {
+                continue;
+            };
+
+            if resolved.cluster.is_none() {
+                resolved.cluster = non_empty_str(ctx.get("cluster"));
+            }
+            if resolved.user.is_none() {
+                resolved.user = non_empty_str(ctx.get("user"));
+            }
+            if resolved.namespace.is_none() {
+                resolved.namespace = non_empty_str(ctx.get("namespace"));
+            }
+        }
+    }
+
+    Some(resolved)
+}
+
+fn non_empty_str(value: Option<&serde_yaml::Value>) -> Option<String> {
+    value.and_then(|v| v.as_str()).filter(|s| !s.is_empty()).map(str::to_string)
+}
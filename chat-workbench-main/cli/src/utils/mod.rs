@@ -0,0 +1,17 @@
+pub mod aws_config;
+pub mod backend;
+pub mod config_migration;
+pub mod coverage;
+pub mod env_profile;
+pub mod executor;
+pub mod gcp_config;
+pub mod kubeconfig;
+pub mod logger;
+pub mod notifier;
+pub mod prompts;
+pub mod recipe;
+pub mod stack_graph;
+pub mod supervisor;
+pub mod telemetry;
+pub mod test_report;
+pub mod watch;
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::config::NotifierConfig;
+
+/// Which phase of a command's lifecycle a notification describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventKind {
+    Started,
+    Succeeded,
+    Failed,
+}
+
+impl EventKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::Started => "started",
+            EventKind::Succeeded => "succeeded",
+            EventKind::Failed => "failed",
+        }
+    }
+}
+
+/// A structured outcome event for a command run, sent to configured sinks.
+#[derive(Debug, Clone)]
+pub struct NotificationEvent {
+    pub kind: EventKind,
+    pub command: String,
+    pub environment: String,
+    pub duration: Duration,
+    /// Tail of captured stderr, only populated for `Failed` events.
+    pub stderr_tail: Option<String>,
+}
+
+impl NotificationEvent {
+    pub fn started(command: impl Into<String>, environment: impl Into<String>) -> Self {
+        Self {
+            kind: EventKind::Started,
+            command: command.into(),
+            environment: environment.into(),
+            duration: Duration::ZERO,
+            stderr_tail: None,
+        }
+    }
+
+    pub fn succeeded(command: impl Into<String>, environment: impl Into<String>, duration: Duration) -> Self {
+        Self {
+            kind: EventKind::Succeeded,
+            command: command.into(),
+            environment: environment.into(),
+            duration,
+            stderr_tail: None,
+        }
+    }
+
+    pub fn failed(
+        command: impl Into<String>,
+        environment: impl Into<String>,
+        duration: Duration,
+        stderr_tail: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind: EventKind::Failed,
+            command: command.into(),
+            environment: environment.into(),
+            duration,
+            stderr_tail: Some(stderr_tail.into()),
+        }
+    }
+}
+
+/// Sends structured outcome events to the sinks configured per-environment.
+/// Notifications are always best-effort: a webhook that is down or errors
+/// must never fail the underlying command.
+pub struct Notifier {
+    config: Option<NotifierConfig>,
+    dry_run: bool,
+}
+
+impl Notifier {
+    pub fn new(config: Option<NotifierConfig>, dry_run: bool) -> Self {
+        Self { config, dry_run }
+    }
+
+    pub async fn notify(&self, event: NotificationEvent) {
+        if self.dry_run {
+            return;
+        }
+
+        let Some(config) = &self.config else {
+            return;
+        };
+
+        if config.on_failure_only && event.kind != EventKind::Failed {
+            return;
+        }
+
+        if !config.events.is_empty() && !config.events.contains(&event.kind) {
+            return;
+        }
+
+        if let Err(e) = self.send(config, &event).await {
+            crate::utils::logger::debug(
+                &format!("Notifier: failed to deliver webhook: {}", e),
+                true,
+            );
+        }
+    }
+
+    async fn send(&self, config: &NotifierConfig, event: &NotificationEvent) -> anyhow::Result<()> {
+        let payload = build_payload(config, event);
+
+        let client = reqwest::Client::new();
+        client
+            .post(&config.webhook_url)
+            .json(&payload)
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
+/// Build the outgoing JSON body. Slack/Discord incoming webhooks both accept
+/// a top-level `text` field, so a generic HTTP POST sink and chat webhooks
+/// can share one payload shape.
+fn build_payload(config: &NotifierConfig, event: &NotificationEvent) -> serde_json::Value {
+    let mut text = format!(
+        "[{}] `{}` {} in `{}` ({:.1}s)",
+        event.kind.as_str(),
+        event.command,
+        event.kind.as_str(),
+        event.environment,
+        event.duration.as_secs_f64(),
+    );
+
+    if let Some(tail) = &event.stderr_tail {
+        if !tail.is_empty() {
+            text.push_str(&format!("\n```\n{}\n```", tail));
+        }
+    }
+
+    serde_json::json!({
+        "text": text,
+        "kind": event.kind.as_str(),
+        "command": event.command,
+        "environment": event.environment,
+        "duration_ms": event.duration.as_millis() as u64,
+        "stderr_tail": event.stderr_tail,
+        "sink": config.sink.as_str(),
+    })
+}
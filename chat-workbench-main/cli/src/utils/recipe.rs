@@ -0,0 +1,237 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::ComponentConfig;
+
+/// cargo-chef-style flags controlling how the dependency layer is built,
+/// named after cargo-chef's own `cook`/`prepare` CLI surface so the recipe
+/// format stays recognizable to anyone who has used it directly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildRecipe {
+    pub component: String,
+    pub path: String,
+    pub language: String,
+    pub package_manager: String,
+    /// Manifest/lockfile paths (relative to `path`) that were hashed into
+    /// this recipe, in `manifest_candidates`' fixed order so recipe JSON is
+    /// reproducible across runs.
+    pub manifest_files: Vec<String>,
+    /// sha256 of each manifest file, keyed by its relative path.
+    pub manifest_hashes: BTreeMap<String, String>,
+    pub profile: String,
+    #[serde(default)]
+    pub features: Vec<String>,
+    pub target: Option<String>,
+    #[serde(default)]
+    pub workspace: bool,
+    #[serde(default)]
+    pub offline: bool,
+    #[serde(default)]
+    pub locked: bool,
+}
+
+impl BuildRecipe {
+    /// A stable fingerprint of the dependency graph: unchanged manifests
+    /// (and unchanged cook flags) produce the same recipe JSON byte-for-byte,
+    /// which is what lets Docker reuse the cached dependency layer.
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.to_json().unwrap_or_default());
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// Serialize with a fixed key order (struct fields in declaration order,
+    /// `manifest_hashes` sorted by path) and a trailing newline so repeated
+    /// `prepare` runs over an unchanged manifest set emit an identical file.
+    pub fn to_json(&self) -> Result<String> {
+        let mut json = serde_json::to_string_pretty(self).context("Failed to serialize build recipe")?;
+        json.push('\n');
+        Ok(json)
+    }
+}
+
+/// Manifest/lockfile names scanned per package manager, relative to the
+/// component's own directory. Listed in a fixed order so hashing is
+/// deterministic regardless of directory-listing order.
+fn manifest_candidates(package_manager: &str) -> &'static [&'static str] {
+    match package_manager {
+        "cargo" => &["Cargo.toml", "Cargo.lock"],
+        "npm" => &["package.json", "package-lock.json"],
+        "yarn" => &["package.json", "yarn.lock"],
+        "pnpm" => &["package.json", "pnpm-lock.yaml"],
+        "uv" => &["pyproject.toml", "uv.lock"],
+        "pip" => &["pyproject.toml", "requirements.txt"],
+        _ => &[],
+    }
+}
+
+/// Scan a component's manifests and emit a `BuildRecipe` capturing its
+/// dependency graph and cook flags, mirroring cargo-chef's `prepare` step.
+pub fn prepare_recipe(
+    comp_config: &ComponentConfig,
+    profile: String,
+    features: Vec<String>,
+    target: Option<String>,
+    workspace: bool,
+    offline: bool,
+    locked: bool,
+) -> Result<BuildRecipe> {
+    let comp_path = Path::new(&comp_config.path);
+    let mut manifest_files = Vec::new();
+    let mut manifest_hashes = BTreeMap::new();
+
+    for candidate in manifest_candidates(&comp_config.package_manager) {
+        let full_path = comp_path.join(candidate);
+        if !full_path.exists() {
+            continue;
+        }
+
+        let content = std::fs::read(&full_path)
+            .with_context(|| format!("Failed to read manifest: {}", full_path.display()))?;
+        let checksum = format!("{:x}", Sha256::digest(&content));
+
+        manifest_files.push(candidate.to_string());
+        manifest_hashes.insert(candidate.to_string(), checksum);
+    }
+
+    Ok(BuildRecipe {
+        component: comp_config.name.clone(),
+        path: comp_config.path.clone(),
+        language: comp_config.language.clone(),
+        package_manager: comp_config.package_manager.clone(),
+        manifest_files,
+        manifest_hashes,
+        profile,
+        features,
+        target,
+        workspace,
+        offline,
+        locked,
+    })
+}
+
+/// Write a recipe's JSON to `dest`, creating parent directories as needed.
+pub fn write_recipe(recipe: &BuildRecipe, dest: &Path) -> Result<()> {
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create recipe directory: {}", parent.display()))?;
+    }
+    std::fs::write(dest, recipe.to_json()?)
+        .with_context(|| format!("Failed to write recipe: {}", dest.display()))
+}
+
+pub fn read_recipe(path: &Path) -> Result<BuildRecipe> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read recipe: {}", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("Invalid recipe JSON: {}", path.display()))
+}
+
+/// The dependency-only install command for a recipe's package manager,
+/// honoring `offline`/`locked` the way the matching native tool would.
+pub fn cook_command(recipe: &BuildRecipe) -> (String, Vec<String>) {
+    match recipe.package_manager.as_str() {
+        "cargo" => {
+            let mut args = vec!["fetch".to_string()];
+            if recipe.locked {
+                args.push("--locked".to_string());
+            }
+            if recipe.offline {
+                args.push("--offline".to_string());
+            }
+            ("cargo".to_string(), args)
+        }
+        "npm" => {
+            let mut args = vec!["ci".to_string()];
+            if recipe.offline {
+                args.push("--offline".to_string());
+            }
+            ("npm".to_string(), args)
+        }
+        "yarn" => {
+            let mut args = vec!["install".to_string(), "--frozen-lockfile".to_string()];
+            if recipe.offline {
+                args.push("--offline".to_string());
+            }
+            ("yarn".to_string(), args)
+        }
+        "pnpm" => {
+            let mut args = vec!["install".to_string(), "--frozen-lockfile".to_string()];
+            if recipe.offline {
+                args.push("--offline".to_string());
+            }
+            ("pnpm".to_string(), args)
+        }
+        "uv" => {
+            let mut args = vec!["sync".to_string()];
+            if recipe.locked {
+                args.push("--locked".to_string());
+            }
+            if recipe.offline {
+                args.push("--offline".to_string());
+            }
+            ("uv".to_string(), args)
+        }
+        "pip" => ("pip".to_string(), vec!["install".to_string(), "-r".to_string(), "requirements.txt".to_string()]),
+        other => (other.to_string(), vec!["install".to_string()]),
+    }
+}
+
+/// Base image per package manager, used only for generated-Dockerfile
+/// scaffolding; real projects are expected to override this stage.
+fn base_image(package_manager: &str) -> &'static str {
+    match package_manager {
+        "cargo" => "rust:1-slim",
+        "npm" | "yarn" | "pnpm" => "node:20-slim",
+        "uv" | "pip" => "python:3.12-slim",
+        _ => "debian:stable-slim",
+    }
+}
+
+/// Generate a two-stage Dockerfile where the dependency layer is built from
+/// the recipe alone (so it's only invalidated by manifest changes) and the
+/// application layer is copied in afterwards. Output contains no timestamps
+/// or absolute paths, so an unchanged recipe produces byte-identical text.
+pub fn generate_dockerfile(recipe: &BuildRecipe) -> String {
+    let (cook_cmd, cook_args) = cook_command(recipe);
+    let cook_line = std::iter::once(cook_cmd.as_str())
+        .chain(cook_args.iter().map(|a| a.as_str()))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let copy_manifests: String = recipe
+        .manifest_files
+        .iter()
+        .map(|f| format!("COPY {} ./{}", f, f))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let image = base_image(&recipe.package_manager);
+
+    format!(
+        "# syntax=docker/dockerfile:1\n\
+         # Generated by `cwb dev build --cached` from recipe for component '{component}'.\n\
+         # Dependency layer only changes when {manifests} change.\n\
+         FROM {image} AS cacher\n\
+         WORKDIR /app\n\
+         {copy_manifests}\n\
+         RUN {cook_line}\n\n\
+         FROM {image} AS builder\n\
+         WORKDIR /app\n\
+         COPY --from=cacher /app /app\n\
+         COPY . .\n",
+        component = recipe.component,
+        manifests = recipe.manifest_files.join(", "),
+        image = image,
+        copy_manifests = copy_manifests,
+        cook_line = cook_line,
+    )
+}
+
+/// Where `cwb dev build --cached` stores generated recipe/Dockerfile
+/// artifacts for a component, so repeated runs overwrite the same path.
+pub fn cache_dir(comp_config: &ComponentConfig) -> PathBuf {
+    Path::new(&comp_config.path).join(".cwb-cache")
+}
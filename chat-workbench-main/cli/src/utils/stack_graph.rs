@@ -0,0 +1,131 @@
+//! Parses a CDK cloud-assembly manifest into a dependency graph of
+//! CloudFormation stacks, so `deploy --all`/`destroy --all` can schedule
+//! stacks in dependency order instead of handing `--all` to the CDK and
+//! hoping it orders things the way we want.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+/// One CloudFormation stack and the other stacks in the assembly it depends on.
+#[derive(Debug, Clone)]
+pub struct StackNode {
+    pub name: String,
+    pub depends_on: Vec<String>,
+}
+
+/// Parse `<cdk_out_dir>/manifest.json`, keeping only
+/// `aws:cloudformation:stack` artifacts and dependencies that are themselves
+/// stacks (asset-publishing and other non-stack artifacts don't matter for
+/// deploy/destroy ordering).
+pub fn parse_manifest(cdk_out_dir: &Path) -> Result<Vec<StackNode>> {
+    let manifest_path = cdk_out_dir.join("manifest.json");
+    let content = std::fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Failed to read {}", manifest_path.display()))?;
+    let manifest: serde_json::Value = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", manifest_path.display()))?;
+
+    let artifacts = manifest
+        .get("artifacts")
+        .and_then(|v| v.as_object())
+        .context("manifest.json has no 'artifacts' object")?;
+
+    let stack_names: HashSet<String> = artifacts
+        .iter()
+        .filter(|(_, v)| v.get("type").and_then(|t| t.as_str()) == Some("aws:cloudformation:stack"))
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    let nodes = stack_names
+        .iter()
+        .map(|name| {
+            let depends_on = artifacts[name]
+                .get("dependencies")
+                .and_then(|d| d.as_array())
+                .map(|deps| {
+                    deps.iter()
+                        .filter_map(|d| d.as_str())
+                        .filter(|d| stack_names.contains(*d))
+                        .map(|d| d.to_string())
+                        .collect()
+                })
+                .unwrap_or_default();
+            StackNode { name: name.clone(), depends_on }
+        })
+        .collect();
+
+    Ok(nodes)
+}
+
+/// Group stacks into topologically-ordered "waves": every stack in wave N
+/// depends only on stacks in waves before it, so everything within a wave
+/// can run concurrently. Kahn's algorithm, like `executor::execute_dag`'s
+/// own cycle check, but surfacing the levels rather than just validating them.
+pub fn topological_waves(nodes: &[StackNode]) -> Result<Vec<Vec<String>>> {
+    let by_name: HashMap<&str, &StackNode> = nodes.iter().map(|n| (n.name.as_str(), n)).collect();
+    let mut in_degree: HashMap<&str, usize> =
+        nodes.iter().map(|n| (n.name.as_str(), n.depends_on.len())).collect();
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+
+    for node in nodes {
+        for dep in &node.depends_on {
+            if !by_name.contains_key(dep.as_str()) {
+                anyhow::bail!("Stack '{}' depends on unknown stack '{}'", node.name, dep);
+            }
+            dependents.entry(dep.as_str()).or_default().push(node.name.as_str());
+        }
+    }
+
+    let mut waves = Vec::new();
+    let mut frontier: Vec<&str> = in_degree.iter().filter(|(_, &d)| d == 0).map(|(&n, _)| n).collect();
+    frontier.sort();
+    let mut visited = 0;
+
+    while !frontier.is_empty() {
+        visited += frontier.len();
+        waves.push(frontier.iter().map(|s| s.to_string()).collect::<Vec<_>>());
+
+        let mut next = Vec::new();
+        for &id in &frontier {
+            if let Some(deps) = dependents.get(id) {
+                for &dep in deps {
+                    let deg = in_degree.get_mut(dep).unwrap();
+                    *deg -= 1;
+                    if *deg == 0 {
+                        next.push(dep);
+                    }
+                }
+            }
+        }
+        next.sort();
+        frontier = next;
+    }
+
+    if visited != nodes.len() {
+        anyhow::bail!("Stack dependency graph has a cycle");
+    }
+
+    Ok(waves)
+}
+
+/// Reverse every dependency edge, so running the graph executor over the
+/// result destroys stacks only once everything that depended on them is
+/// already gone — the mirror image of deploy order.
+pub fn reversed(nodes: &[StackNode]) -> Vec<StackNode> {
+    let mut reverse_deps: HashMap<String, Vec<String>> = HashMap::new();
+    for node in nodes {
+        reverse_deps.entry(node.name.clone()).or_default();
+        for dep in &node.depends_on {
+            reverse_deps.entry(dep.clone()).or_default().push(node.name.clone());
+        }
+    }
+
+    nodes
+        .iter()
+        .map(|n| StackNode {
+            name: n.name.clone(),
+            depends_on: reverse_deps.remove(&n.name).unwrap_or_default(),
+        })
+        .collect()
+}
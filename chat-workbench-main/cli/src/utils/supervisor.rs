@@ -0,0 +1,209 @@
+//! Supervises a set of long-running processes (dev servers), interleaving
+//! their stdout/stderr with a colored `[label]` prefix and keeping them all
+//! alive concurrently, unlike `executor::execute_parallel_streaming` which
+//! is built around commands that are expected to finish.
+//!
+//! Ctrl-C/SIGTERM tears every process down gracefully; so does any one
+//! process exiting non-zero, since a dev server dying usually means the
+//! others are no longer useful either.
+
+use std::process::Stdio;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use colored::{Color, Colorize};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::{mpsc, oneshot, Mutex};
+
+use super::logger;
+
+const COLORS: [Color; 6] = [Color::Cyan, Color::Magenta, Color::Yellow, Color::Green, Color::Blue, Color::Red];
+
+/// One process to keep running as part of `supervise`.
+#[derive(Debug, Clone)]
+pub struct SupervisedProcess {
+    pub label: String,
+    pub cmd: String,
+    pub args: Vec<String>,
+    pub working_dir: Option<std::path::PathBuf>,
+    pub envs: Vec<(String, String)>,
+}
+
+/// Why a supervised process stopped.
+enum Outcome {
+    Exited(std::process::ExitStatus),
+    Killed,
+    SpawnFailed(anyhow::Error),
+}
+
+/// Start every process concurrently and keep them running until:
+/// - the user sends Ctrl-C/SIGTERM, in which case every process is killed
+///   and awaited before returning `Ok(())`, or
+/// - any one process exits non-zero or fails to start, in which case the
+///   rest are killed and awaited and an error naming the first failure is
+///   returned. A process that exits successfully is logged while the others
+///   keep running; `Ok(())` is returned once every process has exited cleanly.
+pub async fn supervise(processes: Vec<SupervisedProcess>) -> Result<()> {
+    if processes.is_empty() {
+        return Ok(());
+    }
+
+    let write_lock = Arc::new(Mutex::new(()));
+    let (done_tx, mut done_rx) = mpsc::unbounded_channel::<(usize, Outcome)>();
+    let mut kill_switches = Vec::with_capacity(processes.len());
+    let mut labels = Vec::with_capacity(processes.len());
+
+    for (idx, proc) in processes.into_iter().enumerate() {
+        labels.push(proc.label.clone());
+        let (kill_tx, kill_rx) = oneshot::channel::<()>();
+        kill_switches.push(Some(kill_tx));
+
+        let done_tx = done_tx.clone();
+        let write_lock = write_lock.clone();
+        let color = COLORS[idx % COLORS.len()];
+
+        tokio::spawn(async move {
+            let outcome = run_one(proc, color, write_lock, kill_rx).await;
+            let _ = done_tx.send((idx, outcome));
+        });
+    }
+    drop(done_tx);
+
+    let mut remaining = labels.len();
+    let mut first_failure: Option<String> = None;
+    let mut shutting_down = false;
+
+    #[cfg(unix)]
+    let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+        .context("Failed to install SIGTERM handler")?;
+
+    loop {
+        if remaining == 0 {
+            break;
+        }
+
+        #[cfg(unix)]
+        let shutdown_signal = async {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = sigterm.recv() => {}
+            }
+        };
+        #[cfg(not(unix))]
+        let shutdown_signal = tokio::signal::ctrl_c();
+
+        tokio::select! {
+            _ = shutdown_signal, if !shutting_down => {
+                logger::info("Shutting down development servers...");
+                shutting_down = true;
+                kill_all(&mut kill_switches);
+            }
+            Some((idx, outcome)) = done_rx.recv() => {
+                remaining -= 1;
+                match outcome {
+                    Outcome::Exited(status) if status.success() => {
+                        logger::info(&format!("[{}] exited", labels[idx]));
+                    }
+                    Outcome::Exited(status) => {
+                        logger::error(&format!("[{}] exited with {:?}", labels[idx], status.code()));
+                        first_failure.get_or_insert_with(|| {
+                            format!("'{}' exited with code {:?}", labels[idx], status.code())
+                        });
+                        if !shutting_down {
+                            shutting_down = true;
+                            kill_all(&mut kill_switches);
+                        }
+                    }
+                    Outcome::SpawnFailed(err) => {
+                        logger::error(&format!("[{}] failed to start: {}", labels[idx], err));
+                        first_failure.get_or_insert_with(|| format!("'{}' failed to start: {}", labels[idx], err));
+                        if !shutting_down {
+                            shutting_down = true;
+                            kill_all(&mut kill_switches);
+                        }
+                    }
+                    Outcome::Killed => {}
+                }
+            }
+        }
+    }
+
+    match first_failure {
+        Some(reason) => anyhow::bail!("Development server supervisor stopped: {}", reason),
+        None => Ok(()),
+    }
+}
+
+fn kill_all(kill_switches: &mut [Option<oneshot::Sender<()>>]) {
+    for switch in kill_switches.iter_mut() {
+        if let Some(tx) = switch.take() {
+            let _ = tx.send(());
+        }
+    }
+}
+
+async fn run_one(
+    proc: SupervisedProcess,
+    color: Color,
+    write_lock: Arc<Mutex<()>>,
+    kill_rx: oneshot::Receiver<()>,
+) -> Outcome {
+    let mut command = Command::new(&proc.cmd);
+    command.args(&proc.args);
+    command.stdout(Stdio::piped());
+    command.stderr(Stdio::piped());
+    for (key, value) in &proc.envs {
+        command.env(key, value);
+    }
+    if let Some(dir) = &proc.working_dir {
+        command.current_dir(dir);
+    }
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(err) => return Outcome::SpawnFailed(anyhow::anyhow!(err).context(format!("Failed to start {}", proc.label))),
+    };
+
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+
+    if let Some(stdout) = stdout {
+        spawn_log_pump(stdout, proc.label.clone(), color, write_lock.clone(), false);
+    }
+    if let Some(stderr) = stderr {
+        spawn_log_pump(stderr, proc.label.clone(), color, write_lock, true);
+    }
+
+    tokio::select! {
+        status = child.wait() => {
+            match status {
+                Ok(status) => Outcome::Exited(status),
+                Err(err) => Outcome::SpawnFailed(anyhow::anyhow!(err)),
+            }
+        }
+        _ = kill_rx => {
+            let _ = child.kill().await;
+            let _ = child.wait().await;
+            Outcome::Killed
+        }
+    }
+}
+
+fn spawn_log_pump<R>(reader: R, label: String, color: Color, write_lock: Arc<Mutex<()>>, is_stderr: bool)
+where
+    R: tokio::io::AsyncRead + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut lines = BufReader::new(reader).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            let _guard = write_lock.lock().await;
+            let prefix = format!("[{}]", label).color(color).bold();
+            if is_stderr {
+                eprintln!("{} {}", prefix, line);
+            } else {
+                println!("{} {}", prefix, line);
+            }
+        }
+    });
+}
@@ -0,0 +1,149 @@
+//! Opt-in failure telemetry: when a streamed command fails, offer to package
+//! it (command, exit code, a stderr tail, tool version) into a JSON report
+//! and upload it to a configured destination, returning a key the user can
+//! paste into a bug report. Mirrors how editors ship crash reports to object
+//! storage rather than asking a user to paste raw terminal output. Gated
+//! behind `EnvConfig.telemetry_config.enable` plus an interactive prompt, so
+//! nothing leaves the machine without explicit consent.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::config::{TelemetryConfig, TelemetryDestination};
+use super::{executor::CommandExecutor, logger, prompts};
+
+/// Trailing stderr lines packaged into a report; enough to see the actual
+/// failure without uploading an entire noisy build log.
+const STDERR_TAIL_LINES: usize = 50;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FailureReport {
+    pub command: String,
+    pub exit_code: Option<i32>,
+    pub stderr_tail: String,
+    pub tool_version: String,
+    pub environment: String,
+}
+
+impl FailureReport {
+    pub fn new(
+        command: impl Into<String>,
+        exit_code: Option<i32>,
+        stderr: &str,
+        environment: impl Into<String>,
+    ) -> Self {
+        let tail_lines: Vec<&str> = stderr.lines().rev().take(STDERR_TAIL_LINES).collect();
+        let stderr_tail: String = tail_lines.into_iter().rev().collect::<Vec<_>>().join("\n");
+
+        Self {
+            command: command.into(),
+            exit_code,
+            stderr_tail: demangle_backtrace(&stderr_tail),
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            environment: environment.into(),
+        }
+    }
+}
+
+/// Replace every mangled Rust symbol (`_ZN...`/`_R...`, the v0 and legacy
+/// mangling prefixes) in a backtrace with its demangled form, so the
+/// uploaded report is readable without the reporter needing `rustc-demangle`
+/// on hand themselves.
+fn demangle_backtrace(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            line.split(' ')
+                .map(|token| {
+                    if token.starts_with("_ZN") || token.starts_with("_R") {
+                        rustc_demangle::demangle(token).to_string()
+                    } else {
+                        token.to_string()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// If telemetry is enabled for this environment, prompt for consent and
+/// upload `report` on approval. A no-op (no prompt at all) when telemetry
+/// isn't configured or isn't enabled, so this is safe to call unconditionally
+/// after any streamed command failure.
+pub async fn offer_upload(
+    report: &FailureReport,
+    config: Option<&TelemetryConfig>,
+    executor: &CommandExecutor,
+) -> Result<()> {
+    let Some(config) = config else { return Ok(()) };
+    if !config.enable {
+        return Ok(());
+    }
+
+    let consented = prompts::confirm(
+        "Upload a failure report (command, exit code, stderr tail, tool version) to help diagnose this?",
+        false,
+    )?;
+    if !consented {
+        return Ok(());
+    }
+
+    let key = upload(report, &config.destination, executor).await?;
+    logger::success(&format!("Uploaded failure report. Share this key in your bug report: {}", key));
+    Ok(())
+}
+
+async fn upload(
+    report: &FailureReport,
+    destination: &TelemetryDestination,
+    executor: &CommandExecutor,
+) -> Result<String> {
+    let key = format!("cwb-failure-{}.json", report_id());
+    let json = serde_json::to_string_pretty(report).context("Failed to serialize failure report")?;
+
+    match destination {
+        TelemetryDestination::S3 { bucket, prefix } => {
+            let object_key = match prefix {
+                Some(prefix) => format!("{}/{}", prefix.trim_end_matches('/'), key),
+                None => key.clone(),
+            };
+            let dest = format!("s3://{}/{}", bucket, object_key);
+
+            let tmp_path = std::env::temp_dir().join(&key);
+            std::fs::write(&tmp_path, &json)
+                .with_context(|| format!("Failed to write temporary report: {}", tmp_path.display()))?;
+
+            let tmp_path_str = tmp_path.to_string_lossy().to_string();
+            let result = executor
+                .execute("aws", &["s3", "cp", &tmp_path_str, &dest, "--only-show-errors"], None)
+                .await;
+
+            let _ = std::fs::remove_file(&tmp_path);
+            result.with_context(|| format!("Failed to upload failure report to {}", dest))?;
+
+            Ok(object_key)
+        }
+        TelemetryDestination::Http { url } => {
+            let client = reqwest::Client::new();
+            client
+                .post(url)
+                .json(report)
+                .send()
+                .await
+                .context("Failed to upload failure report")?
+                .error_for_status()
+                .context("Failure report upload was rejected")?;
+            Ok(key)
+        }
+    }
+}
+
+fn report_id() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
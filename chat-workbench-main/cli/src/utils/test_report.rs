@@ -0,0 +1,160 @@
+//! Machine-readable test reporting for `dev test`, modeled on Deno's
+//! structured test-event model (`TestMessage::Plan/Wait/Result`): each
+//! component's run is reduced to one `TestRecord` (status derived from the
+//! child's exit code, `Instant`-measured duration, command, and filter used),
+//! then handed to a `TestReporter` selected by `--reporter` that either
+//! prints the human summary or emits a CI-ingestible report file.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use super::logger;
+
+/// A component's outcome, mirroring Deno's `TestResult::Ok/Ignored/Failed`
+/// at the granularity `execute_streaming`/`execute_dag` give us: a child
+/// exit code, not per-test results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    /// Never ran because a dependency (`ComponentConfig.depends_on`) failed.
+    Skipped,
+}
+
+/// One component's test run, ready to be rendered by any `TestReporter`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TestRecord {
+    pub component: String,
+    pub status: TestStatus,
+    pub duration_ms: u128,
+    pub command: String,
+    pub filter: Option<String>,
+}
+
+impl TestRecord {
+    pub fn new(
+        component: impl Into<String>,
+        status: TestStatus,
+        duration: Duration,
+        command: impl Into<String>,
+        filter: Option<&str>,
+    ) -> Self {
+        Self {
+            component: component.into(),
+            status,
+            duration_ms: duration.as_millis(),
+            command: command.into(),
+            filter: filter.map(|s| s.to_string()),
+        }
+    }
+}
+
+/// `--reporter` choice for `dev test`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum ReporterKind {
+    /// Log a one-line pass/fail/skip summary, the way `dev test` always has.
+    Pretty,
+    /// Write a rolled-up JSON array to `test-report.json`.
+    Json,
+    /// Write a JUnit `<testsuites>` XML document to `test-report.xml`.
+    Junit,
+}
+
+/// Render a finished `dev test` run's `TestRecord`s.
+pub trait TestReporter {
+    fn report(&self, records: &[TestRecord]) -> Result<()>;
+}
+
+pub struct PrettyReporter;
+
+impl TestReporter for PrettyReporter {
+    fn report(&self, records: &[TestRecord]) -> Result<()> {
+        let passed = records.iter().filter(|r| r.status == TestStatus::Passed).count();
+        let failed = records.iter().filter(|r| r.status == TestStatus::Failed).count();
+        let skipped = records.iter().filter(|r| r.status == TestStatus::Skipped).count();
+        logger::info(&format!("Test summary: {} passed, {} failed, {} skipped", passed, failed, skipped));
+        Ok(())
+    }
+}
+
+pub struct JsonReporter {
+    pub path: PathBuf,
+}
+
+impl TestReporter for JsonReporter {
+    fn report(&self, records: &[TestRecord]) -> Result<()> {
+        let json = serde_json::to_string_pretty(records).context("Failed to serialize test report")?;
+        std::fs::write(&self.path, json)
+            .with_context(|| format!("Failed to write test report: {}", self.path.display()))?;
+        logger::info(&format!("Wrote JSON test report to {}", self.path.display()));
+        Ok(())
+    }
+}
+
+pub struct JunitReporter {
+    pub path: PathBuf,
+}
+
+impl TestReporter for JunitReporter {
+    fn report(&self, records: &[TestRecord]) -> Result<()> {
+        let failures = records.iter().filter(|r| r.status == TestStatus::Failed).count();
+        let total_time: f64 = records.iter().map(|r| r.duration_ms as f64 / 1000.0).sum();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuites tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+            records.len(),
+            failures,
+            total_time,
+        ));
+        for record in records {
+            let time = record.duration_ms as f64 / 1000.0;
+            xml.push_str(&format!(
+                "  <testsuite name=\"{}\" tests=\"1\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">\n",
+                xml_escape(&record.component),
+                (record.status == TestStatus::Failed) as u8,
+                (record.status == TestStatus::Skipped) as u8,
+                time,
+            ));
+            xml.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\">\n",
+                xml_escape(&record.command),
+                xml_escape(&record.component),
+                time,
+            ));
+            match record.status {
+                TestStatus::Failed => xml.push_str("      <failure message=\"test command exited non-zero\" />\n"),
+                TestStatus::Skipped => xml.push_str("      <skipped />\n"),
+                TestStatus::Passed => {}
+            }
+            xml.push_str("    </testcase>\n");
+            xml.push_str("  </testsuite>\n");
+        }
+        xml.push_str("</testsuites>\n");
+
+        std::fs::write(&self.path, xml)
+            .with_context(|| format!("Failed to write JUnit report: {}", self.path.display()))?;
+        logger::info(&format!("Wrote JUnit test report to {}", self.path.display()));
+        Ok(())
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Build the `TestReporter` for `--reporter`, defaulting each file-based
+/// format's output alongside the current working directory.
+pub fn reporter_for(kind: ReporterKind) -> Box<dyn TestReporter> {
+    match kind {
+        ReporterKind::Pretty => Box::new(PrettyReporter),
+        ReporterKind::Json => Box::new(JsonReporter { path: PathBuf::from("test-report.json") }),
+        ReporterKind::Junit => Box::new(JunitReporter { path: PathBuf::from("test-report.xml") }),
+    }
+}
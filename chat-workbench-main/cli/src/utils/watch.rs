@@ -0,0 +1,113 @@
+//! Cross-cutting `--watch` support for `dev test|lint|typecheck|build`,
+//! modeled on Deno's file-watcher loop: debounce raw filesystem events into
+//! settled batches, then map each changed path back to its owning component
+//! by longest matching `ComponentConfig.path` prefix. A changed path that
+//! doesn't fall under any watched component path (e.g. a shared root
+//! `config.yaml`) is treated as a shared-config change and affects every
+//! watched component.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher as NotifyWatcher};
+use tokio::sync::mpsc;
+use tokio::time::{sleep, Duration};
+
+use crate::config::ComponentConfig;
+
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// A live recursive filesystem watch over a set of components' paths.
+pub struct Watcher {
+    _inner: RecommendedWatcher,
+    rx: mpsc::UnboundedReceiver<Event>,
+    component_paths: Vec<(String, PathBuf)>,
+}
+
+impl Watcher {
+    /// Recursively watch every `ComponentConfig.path` in `components` that
+    /// exists on disk. Paths are sorted longest-first so the most specific
+    /// component wins when a changed file falls under more than one.
+    pub fn new(components: &HashMap<String, ComponentConfig>) -> Result<Self> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut inner: RecommendedWatcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+            if let Ok(event) = res {
+                let _ = tx.send(event);
+            }
+        })
+        .context("Failed to create file watcher")?;
+
+        let mut component_paths = Vec::new();
+        for comp in components.values() {
+            let path = PathBuf::from(&comp.path);
+            if !path.exists() {
+                continue;
+            }
+            inner
+                .watch(&path, RecursiveMode::Recursive)
+                .with_context(|| format!("Failed to watch '{}'", path.display()))?;
+            // Filesystem events carry absolute paths, so match against the
+            // component path resolved the same way.
+            let path = std::env::current_dir().map(|cwd| cwd.join(&path)).unwrap_or(path);
+            component_paths.push((comp.name.clone(), path));
+        }
+        component_paths.sort_by_key(|(_, path)| std::cmp::Reverse(path.as_os_str().len()));
+
+        Ok(Self { _inner: inner, rx, component_paths })
+    }
+
+    /// Number of paths currently being watched, for the "Watching N paths…" banner.
+    pub fn watched_count(&self) -> usize {
+        self.component_paths.len()
+    }
+
+    /// Wait for the next filesystem event, then keep draining events for up
+    /// to 200ms after each new one, and return the deduplicated, sorted set
+    /// of component names affected by the settled batch. Returns `None`
+    /// once the watcher's channel closes.
+    pub async fn next_batch(&mut self) -> Option<Vec<String>> {
+        let first = self.rx.recv().await?;
+        let mut changed_paths = first.paths;
+
+        loop {
+            tokio::select! {
+                event = self.rx.recv() => {
+                    match event {
+                        Some(event) => changed_paths.extend(event.paths),
+                        None => break,
+                    }
+                }
+                _ = sleep(DEBOUNCE) => break,
+            }
+        }
+
+        let mut affected = HashSet::new();
+        for path in &changed_paths {
+            match self.owning_component(path) {
+                Some(name) => {
+                    affected.insert(name);
+                }
+                None => {
+                    // Shared config or some other out-of-tree path changed;
+                    // re-run every watched component to be safe.
+                    for (name, _) in &self.component_paths {
+                        affected.insert(name.clone());
+                    }
+                    break;
+                }
+            }
+        }
+
+        let mut affected: Vec<String> = affected.into_iter().collect();
+        affected.sort();
+        Some(affected)
+    }
+
+    fn owning_component(&self, path: &Path) -> Option<String> {
+        self.component_paths
+            .iter()
+            .find(|(_, comp_path)| path.starts_with(comp_path))
+            .map(|(name, _)| name.clone())
+    }
+}